@@ -0,0 +1,205 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Day;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt;
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+// A phase whose mean grows by more than this fraction over the baseline counts as a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.1;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Summary {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub summary: Summary,
+}
+
+// How the mean of a phase compares to the baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    // The relative change, e.g. 0.25 for 25% slower.
+    Relative(f64),
+    // The baseline mean is zero, so there is nothing to compare against.
+    NotApplicable,
+}
+
+// Mean durations keyed by day and phase, as saved by a previous run.
+pub type Baseline = HashMap<(u8, Phase), Duration>;
+
+impl Phase {
+    fn parse(raw_phase: &str) -> Option<Phase> {
+        match raw_phase {
+            "parse" => Some(Phase::Parse),
+            "part_one" => Some(Phase::PartOne),
+            "part_two" => Some(Phase::PartTwo),
+            _ => None,
+        }
+    }
+}
+
+impl Summary {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Measurement {
+    // None when the baseline has no mean for this day and phase.
+    pub fn change(&self, baseline: &Baseline) -> Option<Change> {
+        baseline.get(&(self.day, self.phase)).map(|base| {
+            if base.is_zero() {
+                Change::NotApplicable
+            } else {
+                let mean = self.summary.mean.as_secs_f64();
+                Change::Relative((mean - base.as_secs_f64()) / base.as_secs_f64())
+            }
+        })
+    }
+}
+
+impl Change {
+    pub fn is_regression(&self) -> bool {
+        matches!(*self, Change::Relative(change) if change > REGRESSION_THRESHOLD)
+    }
+}
+
+// Times parsing and both parts separately, running each of them `samples` times.
+pub fn measure(day: &Day, input: &str, samples: usize) -> Result<Vec<Measurement>> {
+    let mut parse_samples = Vec::with_capacity(samples);
+    let mut puzzle = day.parse(input)?;
+    for _ in 0..samples {
+        let start = Instant::now();
+        let parsed = black_box(day.parse(black_box(input))?);
+        parse_samples.push(start.elapsed());
+        // Dropping the previous puzzle is not part of parsing.
+        puzzle = parsed;
+    }
+
+    let mut part_one_samples = Vec::with_capacity(samples);
+    let mut part_two_samples = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        black_box(puzzle.part_one()?);
+        part_one_samples.push(start.elapsed());
+    }
+    for _ in 0..samples {
+        let start = Instant::now();
+        black_box(puzzle.part_two()?);
+        part_two_samples.push(start.elapsed());
+    }
+
+    Ok([
+        (Phase::Parse, parse_samples),
+        (Phase::PartOne, part_one_samples),
+        (Phase::PartTwo, part_two_samples),
+    ]
+    .into_iter()
+    .map(|(phase, samples)| Measurement {
+        day: day.number,
+        phase,
+        summary: Summary::from_samples(&samples),
+    })
+    .collect())
+}
+
+// Baselines are stored one phase per line as `<day> <phase> <mean in nanoseconds>`.
+pub fn format_baseline(measurements: &[Measurement]) -> String {
+    measurements
+        .iter()
+        .map(|measurement| {
+            format!(
+                "{} {} {}\n",
+                measurement.day,
+                measurement.phase,
+                measurement.summary.mean.as_nanos()
+            )
+        })
+        .collect()
+}
+
+pub fn parse_baseline(input: &str) -> ParseResult<Baseline> {
+    parse::lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(parse_baseline_line)
+        .collect()
+}
+
+fn parse_baseline_line(line: Line) -> ParseResult<((u8, Phase), Duration)> {
+    let fields: Vec<_> = line.text.split_ascii_whitespace().collect();
+    match fields[..] {
+        [raw_day, raw_phase, raw_mean] => {
+            let day = line.number(raw_day)?;
+            let phase = Phase::parse(raw_phase).ok_or_else(|| {
+                line.error_at(
+                    raw_phase,
+                    ParseErrorKind::UnexpectedToken(raw_phase.to_string()),
+                )
+            })?;
+            let mean = Duration::from_nanos(line.number(raw_mean)?);
+            Ok(((day, phase), mean))
+        }
+        _ => Err(line.error_at(
+            line.text,
+            ParseErrorKind::Expected("a day, a phase and a mean duration"),
+        )),
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part_one"),
+            Phase::PartTwo => write!(f, "part_two"),
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Relative(change) => write!(f, "{:+.1}%", change * 100.0),
+            Change::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use anyhow::anyhow;
+use anyhow::bail;
+use anyhow::Context;
+use anyhow::Result;
+use aoc_2023::bench;
+use aoc_2023::bench::Change;
+use aoc_2023::bench::Measurement;
+use aoc_2023::Day;
+use std::env;
+use std::fs;
+
+const USAGE: &str = "usage: aoc run (--all | --day <N> [--part <1|2>] [--input <PATH>])
+       aoc bench [--day <N>] [--samples <COUNT>] [--save <PATH>] [--baseline <PATH>]";
+
+const DEFAULT_SAMPLES: usize = 10;
+
+enum Selection {
+    All,
+    Single {
+        day: &'static Day,
+        part: Option<u8>,
+        input: Option<String>,
+    },
+}
+
+struct BenchOptions {
+    days: Vec<&'static Day>,
+    samples: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => run(parse_selection(rest)?),
+        Some((command, rest)) if command == "bench" => bench(parse_bench_options(rest)?),
+        _ => bail!(USAGE),
+    }
+}
+
+fn run(selection: Selection) -> Result<()> {
+    match selection {
+        Selection::All => run_all(),
+        Selection::Single { day, part, input } => {
+            let input_path = input.unwrap_or_else(|| day.input_path());
+            let puzzle = day.parse(&read_input(&input_path)?)?;
+            match part {
+                Some(1) => println!("{}", puzzle.part_one()?),
+                Some(2) => println!("{}", puzzle.part_two()?),
+                Some(part) => bail!("there is no part {part}"),
+                None => {
+                    println!("{}", puzzle.part_one()?);
+                    println!("{}", puzzle.part_two()?);
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn run_all() -> Result<()> {
+    println!("{:>3}  {:>20}  {:>20}", "Day", "Part 1", "Part 2");
+    for day in aoc_2023::DAYS.iter() {
+        let (part_one, part_two) = day.solve(&read_input(&day.input_path())?)?;
+        println!("{:>3}  {:>20}  {:>20}", day.number, part_one, part_two);
+    }
+    Ok(())
+}
+
+fn bench(options: BenchOptions) -> Result<()> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(
+            bench::parse_baseline(&read_input(path)?)
+                .with_context(|| format!("could not parse baseline {path}"))?,
+        ),
+        None => None,
+    };
+    let mut measurements = vec![];
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<8}  {:>12}  {:>12}  {:>12}  {:>9}",
+        "Day", "Phase", "Mean", "Median", "Std dev", "Change"
+    );
+    for day in options.days {
+        let input = read_input(&day.input_path())?;
+        for measurement in bench::measure(day, &input, options.samples)? {
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| measurement.change(baseline));
+            if change.is_some_and(|change| change.is_regression()) {
+                regressions += 1;
+            }
+            print_measurement(&measurement, change);
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        fs::write(path, bench::format_baseline(&measurements))
+            .with_context(|| format!("could not save baseline to {path}"))?;
+    }
+    if regressions > 0 {
+        println!(
+            "{regressions} phase(s) regressed by more than {:.0}%",
+            bench::REGRESSION_THRESHOLD * 100.0
+        );
+    }
+    Ok(())
+}
+
+fn print_measurement(measurement: &Measurement, change: Option<Change>) {
+    let change = match change {
+        Some(change) if change.is_regression() => format!("{change} !"),
+        Some(change) => change.to_string(),
+        None => String::new(),
+    };
+    println!(
+        "{:>3}  {:<8}  {:>12}  {:>12}  {:>12}  {:>9}",
+        measurement.day,
+        measurement.phase.to_string(),
+        format!("{:.2?}", measurement.summary.mean),
+        format!("{:.2?}", measurement.summary.median),
+        format!("{:.2?}", measurement.summary.stddev),
+        change
+    );
+}
+
+fn read_input(path: &str) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("could not read input from {path}"))
+}
+
+fn parse_selection(args: &[String]) -> Result<Selection> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {flag}"))
+        };
+        match flag.as_str() {
+            "--all" => all = true,
+            "--day" => day = Some(value()?.parse::<u8>()?),
+            "--part" => part = Some(value()?.parse::<u8>()?),
+            "--input" => input = Some(value()?.to_string()),
+            _ => bail!("unexpected argument {flag}\n{USAGE}"),
+        }
+    }
+
+    match (all, day) {
+        (true, None) if part.is_none() && input.is_none() => Ok(Selection::All),
+        (false, Some(number)) => Ok(Selection::Single {
+            day: aoc_2023::find_day(number).ok_or_else(|| anyhow!("day {number} is not solved"))?,
+            part,
+            input,
+        }),
+        _ => bail!(USAGE),
+    }
+}
+
+fn parse_bench_options(args: &[String]) -> Result<BenchOptions> {
+    let mut day = None;
+    let mut samples = DEFAULT_SAMPLES;
+    let mut save = None;
+    let mut baseline = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("missing value for {flag}"))
+        };
+        match flag.as_str() {
+            "--day" => day = Some(value()?.parse::<u8>()?),
+            "--samples" => samples = value()?.parse()?,
+            "--save" => save = Some(value()?.to_string()),
+            "--baseline" => baseline = Some(value()?.to_string()),
+            _ => bail!("unexpected argument {flag}\n{USAGE}"),
+        }
+    }
+    if samples == 0 {
+        bail!("at least one sample is needed");
+    }
+
+    let days = match day {
+        Some(number) => {
+            vec![aoc_2023::find_day(number).ok_or_else(|| anyhow!("day {number} is not solved"))?]
+        }
+        None => aoc_2023::DAYS.iter().collect(),
+    };
+    Ok(BenchOptions {
+        days,
+        samples,
+        save,
+        baseline,
+    })
+}
@@ -1,22 +1,211 @@
+use crate::graph::Traversal;
+use crate::graph::UnweightedGraph;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::spatial::Orientation;
 use crate::spatial::UPoint2D;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::once;
 
-#[derive(Debug)]
-pub struct Matrix<T> {
-    pub data: Vec<T>,
-    pub width: usize,
+// A rectangular grid stored row by row, indexed with (padded) UPoint2D positions.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
-impl<T> Matrix<T> {
-    pub fn at(&self, at: UPoint2D) -> &'_ T {
-        let idx = (at.row - 1) * self.width + (at.column - 1);
-        &self.data[idx]
+impl<T> Grid<T> {
+    pub fn new(data: Vec<T>, width: usize) -> Self {
+        let height = data.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, data.len(), "grid data is not rectangular");
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    // Every character of the input is mapped to a cell; characters for which
+    // to_cell returns None are reported as unexpected.
+    pub fn parse(input: &str, to_cell: impl FnMut(char) -> Option<T>) -> ParseResult<Self> {
+        let lines: Vec<_> = parse::lines(input).collect();
+        Self::parse_lines(&lines, to_cell)
+    }
+
+    pub fn parse_lines(
+        lines: &[Line],
+        mut to_cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Self> {
+        let width = parse::grid_width(lines)?;
+        let mut data = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            for (offset, c) in line.text.char_indices() {
+                data.push(to_cell(c).ok_or_else(|| line.unexpected_character(offset, c))?);
+            }
+        }
+        Ok(Self::new(data, width))
     }
 
     pub fn get_width(&self) -> usize {
         self.width
     }
+
     pub fn get_height(&self) -> usize {
-        self.data.len() / self.width
+        self.height
+    }
+
+    pub fn contains(&self, at: UPoint2D) -> bool {
+        at.within_bounds(self.width, self.height)
+    }
+
+    pub fn at(&self, at: UPoint2D) -> &'_ T {
+        self.get(at).expect("position outside of the grid")
+    }
+
+    pub fn at_mut(&mut self, at: UPoint2D) -> &'_ mut T {
+        self.get_mut(at).expect("position outside of the grid")
+    }
+
+    pub fn get(&self, at: UPoint2D) -> Option<&'_ T> {
+        self.index_of(at).map(|idx| &self.data[idx])
+    }
+
+    pub fn get_mut(&mut self, at: UPoint2D) -> Option<&'_ mut T> {
+        self.index_of(at).map(|idx| &mut self.data[idx])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = UPoint2D> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |column| UPoint2D::from(row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (UPoint2D, &'_ T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'_ [T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &'_ T>> {
+        (0..self.width).map(move |column| self.data[column..].iter().step_by(self.width))
+    }
+
+    // The 4-connected neighbours that lie inside the grid.
+    pub fn neighbours(&self, at: UPoint2D) -> Vec<UPoint2D> {
+        at.neighbours()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+            .collect()
+    }
+
+    // The 8-connected neighbours that lie inside the grid.
+    pub fn neighbours8(&self, at: UPoint2D) -> Vec<UPoint2D> {
+        at.neighbours8()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+            .collect()
+    }
+
+    // The positions 4-connected to the seeds through cells that can be passed.
+    pub fn flood_fill(
+        &self,
+        seeds: &[UPoint2D],
+        is_passable: impl Fn(&T) -> bool,
+    ) -> HashSet<UPoint2D> {
+        let seeds: Vec<_> = seeds
+            .iter()
+            .cloned()
+            .filter(|&seed| self.get(seed).is_some_and(&is_passable))
+            .collect();
+        GridFill {
+            grid: self,
+            is_passable,
+        }
+        .reachable(&seeds)
+    }
+
+    pub fn map<U>(&self, to_cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(to_cell).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, at: UPoint2D) -> Option<usize> {
+        if self.contains(at) {
+            Some((at.row - 1) * self.width + (at.column - 1))
+        } else {
+            None
+        }
+    }
+}
+
+struct GridFill<'a, T, P> {
+    grid: &'a Grid<T>,
+    is_passable: P,
+}
+
+impl<T, P: Fn(&T) -> bool> UnweightedGraph<UPoint2D> for GridFill<'_, T, P> {
+    fn successors(&self, node: &UPoint2D) -> Vec<UPoint2D> {
+        self.grid
+            .neighbours(*node)
+            .into_iter()
+            .filter(|&neighbour| (self.is_passable)(self.grid.at(neighbour)))
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        let data = self.columns().flatten().cloned().collect();
+        Self {
+            data,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate(&self, orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Clockwise => self.transpose().flip_horizontally(),
+            Orientation::Counterclockwise => self.transpose().flip_vertically(),
+        }
+    }
+
+    // Mirrors the grid left to right.
+    pub fn flip_horizontally(&self) -> Self {
+        let data = self
+            .rows()
+            .flat_map(|row| row.iter().rev())
+            .cloned()
+            .collect();
+        Self { data, ..*self }
+    }
+
+    // Mirrors the grid top to bottom.
+    pub fn flip_vertically(&self) -> Self {
+        let data = self.rows().rev().flatten().cloned().collect();
+        Self { data, ..*self }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows().filter(|row| !row.is_empty()) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
@@ -37,49 +226,209 @@ where
     }
 }
 
-#[derive(Debug)]
-pub struct Cycle {
+// The states from index mu onwards repeat every lambda steps.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle<T> {
     pub mu: usize,     // the length of the prefix
     pub lambda: usize, // the actual cycle length
+    // The lambda states inside the cycle, starting with the one at index mu.
+    pub states: Vec<T>,
+}
+
+// Detectors look for the first repeated state in the sequence, returning
+// None if the sequence ends before any state repeats.
+pub trait CycleDetector<T> {
+    fn find_cycle<S>(&self, sequence: &S) -> Option<Cycle<T>>
+    where
+        S: IntoIterator<Item = T> + Clone;
 }
 
-pub trait CycleDetection {
-    fn detect_cycle(&self) -> Cycle;
+// Floyd's tortoise and hare: constant memory, but the sequence is walked three
+// times and the hare advances twice as fast.
+pub struct Floyd;
+
+// Brent's power-of-two teleporting tortoise: constant memory, and fewer steps
+// than Floyd since the hare only advances once per step.
+pub struct Brent;
+
+// Remembers every state seen, so that the sequence is only walked once.
+pub struct SeenStates;
+
+pub trait CycleDetection: IntoIterator + Clone {
+    fn detect_cycle(&self) -> Option<Cycle<Self::Item>>;
+    fn detect_cycle_with(
+        &self,
+        detector: impl CycleDetector<Self::Item>,
+    ) -> Option<Cycle<Self::Item>>;
 }
 
 impl<T> CycleDetection for T
 where
     T: IntoIterator + Clone,
-    T::Item: Eq,
+    T::Item: Clone + Eq,
 {
-    fn detect_cycle(&self) -> Cycle {
-        let mut tortoise_iter = self.clone().into_iter();
-        let mut hare_iter = self.clone().into_iter().skip(1);
-        let mut tortoise = tortoise_iter.next().unwrap();
-        let mut hare = hare_iter.next().unwrap();
+    fn detect_cycle(&self) -> Option<Cycle<Self::Item>> {
+        self.detect_cycle_with(Brent)
+    }
+
+    fn detect_cycle_with(
+        &self,
+        detector: impl CycleDetector<Self::Item>,
+    ) -> Option<Cycle<Self::Item>> {
+        detector.find_cycle(self)
+    }
+}
+
+impl<T: Clone + Eq> CycleDetector<T> for Floyd {
+    fn find_cycle<S>(&self, sequence: &S) -> Option<Cycle<T>>
+    where
+        S: IntoIterator<Item = T> + Clone,
+    {
+        let mut tortoise_iter = sequence.clone().into_iter();
+        let mut hare_iter = sequence.clone().into_iter().skip(1);
+        let mut tortoise = tortoise_iter.next()?;
+        let mut hare = hare_iter.next()?;
         while tortoise != hare {
-            tortoise = tortoise_iter.next().unwrap();
-            hare_iter.next();
-            hare = hare_iter.next().unwrap();
+            tortoise = tortoise_iter.next()?;
+            hare_iter.next()?;
+            hare = hare_iter.next()?;
         }
 
         let mut mu = 0;
-        tortoise_iter = self.clone().into_iter(); // reset stream
-        tortoise = tortoise_iter.next().unwrap();
-        hare = hare_iter.next().unwrap();
+        tortoise_iter = sequence.clone().into_iter(); // reset stream
+        tortoise = tortoise_iter.next()?;
+        hare = hare_iter.next()?;
         while tortoise != hare {
-            tortoise = tortoise_iter.next().unwrap();
-            hare = hare_iter.next().unwrap();
+            tortoise = tortoise_iter.next()?;
+            hare = hare_iter.next()?;
             mu += 1;
         }
 
         let mut lambda = 1;
-        hare = hare_iter.next().unwrap();
+        hare = hare_iter.next()?;
         while tortoise != hare {
-            hare = hare_iter.next().unwrap();
+            hare = hare_iter.next()?;
             lambda += 1;
         }
 
-        Cycle { mu, lambda }
+        Some(cycle_from(mu, lambda, tortoise, tortoise_iter))
+    }
+}
+
+impl<T: Clone + Eq> CycleDetector<T> for Brent {
+    fn find_cycle<S>(&self, sequence: &S) -> Option<Cycle<T>>
+    where
+        S: IntoIterator<Item = T> + Clone,
+    {
+        let mut hare_iter = sequence.clone().into_iter();
+        let mut tortoise = hare_iter.next()?;
+        let mut hare = hare_iter.next()?;
+        let mut power = 1;
+        let mut lambda = 1;
+        while tortoise != hare {
+            if power == lambda {
+                tortoise = hare.clone();
+                power *= 2;
+                lambda = 0;
+            }
+            hare = hare_iter.next()?;
+            lambda += 1;
+        }
+
+        // With the hare lambda states ahead, both meet at the start of the cycle.
+        let mut mu = 0;
+        let mut tortoise_iter = sequence.clone().into_iter();
+        let mut hare_iter = sequence.clone().into_iter().skip(lambda);
+        tortoise = tortoise_iter.next()?;
+        hare = hare_iter.next()?;
+        while tortoise != hare {
+            tortoise = tortoise_iter.next()?;
+            hare = hare_iter.next()?;
+            mu += 1;
+        }
+
+        Some(cycle_from(mu, lambda, tortoise, tortoise_iter))
+    }
+}
+
+impl<T: Clone + Eq + Hash> CycleDetector<T> for SeenStates {
+    fn find_cycle<S>(&self, sequence: &S) -> Option<Cycle<T>>
+    where
+        S: IntoIterator<Item = T> + Clone,
+    {
+        match walk_until_repeat(sequence.clone(), None) {
+            Walk::Repeated(cycle) => Some(cycle),
+            _ => None,
+        }
+    }
+}
+
+// The state at index n of a sequence that eventually repeats, e.g. a
+// simulation after n rounds. Every state is computed at most once, and as
+// soon as one repeats the answer is read off the cycle instead. None if the
+// sequence ends before reaching either.
+pub fn nth_with_cycle_skip<S>(sequence: S, n: u64) -> Option<S::Item>
+where
+    S: IntoIterator,
+    S::Item: Clone + Eq + Hash,
+{
+    match walk_until_repeat(sequence, Some(n)) {
+        Walk::Reached(state) => Some(state),
+        Walk::Repeated(mut cycle) => {
+            let offset = cycle.offset_of(n).expect("the prefix was walked already");
+            Some(cycle.states.swap_remove(offset))
+        }
+        Walk::Ended => None,
+    }
+}
+
+impl<T> Cycle<T> {
+    // Where in `states` the state at index n of the sequence is, unless it is
+    // still in the prefix.
+    pub fn offset_of(&self, n: u64) -> Option<usize> {
+        let steps_into_cycle = n.checked_sub(self.mu as u64)?;
+        Some((steps_into_cycle % self.lambda as u64) as usize)
     }
+
+    pub fn state_at(&self, n: u64) -> Option<&T> {
+        self.offset_of(n).map(|offset| &self.states[offset])
+    }
+}
+
+enum Walk<T> {
+    Reached(T),
+    Repeated(Cycle<T>),
+    Ended,
+}
+
+// Remembers the states of the sequence until one repeats, or until the one at
+// index stop_at comes up.
+fn walk_until_repeat<T>(sequence: impl IntoIterator<Item = T>, stop_at: Option<u64>) -> Walk<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    for (index, state) in sequence.into_iter().enumerate() {
+        if stop_at == Some(index as u64) {
+            return Walk::Reached(state);
+        }
+        if let Some(&mu) = seen.get(&state) {
+            return Walk::Repeated(Cycle {
+                mu,
+                lambda: index - mu,
+                states: history.split_off(mu),
+            });
+        }
+        seen.insert(state.clone(), index);
+        history.push(state);
+    }
+    Walk::Ended
+}
+
+// Collects the states inside the cycle, given the first one and the rest of
+// the sequence after it.
+fn cycle_from<T>(mu: usize, lambda: usize, first: T, rest: impl Iterator<Item = T>) -> Cycle<T> {
+    let states = once(first).chain(rest.take(lambda - 1)).collect();
+    Cycle { mu, lambda, states }
 }
@@ -0,0 +1,102 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::anyhow;
+use anyhow::Result;
+
+const DIGIT_NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Puzzle = Vec<String>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse::lines(input)
+            .map(parse_line)
+            .collect::<ParseResult<_>>()?)
+    }
+
+    fn part_one(lines: &Self::Puzzle) -> Result<Self::Answer> {
+        sum_calibration_values(lines, extract_calibration_value)
+    }
+
+    fn part_two(lines: &Self::Puzzle) -> Result<Self::Answer> {
+        sum_calibration_values(lines, extract_spelled_calibration_value)
+    }
+}
+
+// Calibration lines only hold digits and the lowercase letters spelling them.
+fn parse_line(line: Line) -> ParseResult<String> {
+    match line
+        .char_indices(line.text)
+        .find(|&(_, char)| !(char.is_ascii_digit() || char.is_ascii_lowercase()))
+    {
+        Some((offset, char)) => Err(line.unexpected_character(offset, char)),
+        None => Ok(line.text.to_string()),
+    }
+}
+
+fn sum_calibration_values(lines: &[String], extract: fn(&str) -> Option<u32>) -> Result<u32> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| extract(line).ok_or_else(|| anyhow!("line {} has no digits", idx + 1)))
+        .sum()
+}
+
+fn extract_calibration_value(line: &str) -> Option<u32> {
+    let digits = line
+        .chars()
+        .filter_map(|char| char.to_digit(10))
+        .collect::<Vec<_>>();
+    let first = digits.first()?;
+    let last = digits.last()?;
+
+    Some(10 * first + last)
+}
+
+fn extract_spelled_calibration_value(line: &str) -> Option<u32> {
+    let first = line.char_indices().find_map(|(idx, char)| {
+        char.to_digit(10).or_else(|| {
+            DIGIT_NAMES
+                .iter()
+                .find(|&candidate| line[idx..].starts_with(candidate))
+                .and_then(|&solution| parse_extended_digit(solution))
+        })
+    })?;
+
+    let last = line
+        .char_indices()
+        .rev()
+        .find_map(|(idx, char)| {
+            char.to_digit(10).or_else(|| {
+                DIGIT_NAMES
+                    .iter()
+                    .find(|&candidate| line[..idx + char.len_utf8()].ends_with(candidate))
+                    .and_then(|&solution| parse_extended_digit(solution))
+            })
+        })
+        .unwrap_or(first);
+
+    Some(10 * first + last)
+}
+
+fn parse_extended_digit(digit: &str) -> Option<u32> {
+    match digit {
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        _ => None,
+    }
+}
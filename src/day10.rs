@@ -1,3 +1,8 @@
+use crate::common::Grid;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use crate::spatial;
 use anyhow::anyhow;
 use anyhow::Result;
@@ -11,6 +16,18 @@ pub struct Field {
 
 pub type Pipe = (spatial::Direction, spatial::Direction);
 
+#[derive(Debug)]
+struct Range {
+    start: usize,
+    size: usize,
+}
+
+#[derive(Debug)]
+struct ClosedLoop {
+    path: Vec<spatial::UPoint2D>,
+    pipes: HashMap<spatial::UPoint2D, Pipe>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 enum RawPipe {
     Horizontal,
@@ -21,33 +38,73 @@ enum RawPipe {
     RightBottom,
 }
 
+enum Tile {
+    Ground,
+    Start,
+    Pipe(Pipe),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Puzzle = (Field, spatial::UPoint2D);
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one((field, start): &Self::Puzzle) -> Result<Self::Answer> {
+        find_length(field, *start)
+    }
+
+    fn part_two((field, start): &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(ClosedLoop::trace_for(field, *start)?.area())
+    }
+}
+
+fn find_length(field: &Field, start: spatial::UPoint2D) -> Result<u64> {
+    let (direction, _) = field
+        .identify_start_pipe(start)
+        .ok_or_else(|| anyhow!("the start is not connected to exactly two pipes"))?;
+    let steps = field
+        .trace_loop(start, direction)
+        .ok_or_else(|| anyhow!("the pipes from the start do not form a loop"))?;
+
+    Ok((steps.len() as u64).div_ceil(2))
+}
+
 impl Field {
+    // None if the pipes lead off the field or into a pipe that doesn't connect back.
     pub fn trace_loop(
         &self,
         start: spatial::UPoint2D,
         direction: spatial::Direction,
-    ) -> Vec<spatial::UPoint2D> {
-        let mut current = start.move_by(direction).unwrap();
+    ) -> Option<Vec<spatial::UPoint2D>> {
+        let mut current = start.move_by(direction)?;
         let mut came_from = -direction;
         let mut loop_points = vec![];
 
         while current != start {
             loop_points.push(current);
-            let directions = self.pipes.get(&current).unwrap();
-            if directions.0 == came_from {
-                current = current.move_by(directions.1).unwrap();
-                came_from = -directions.1;
+            let directions = self.pipes.get(&current)?;
+            let next_direction = if directions.0 == came_from {
+                directions.1
+            } else if directions.1 == came_from {
+                directions.0
             } else {
-                current = current.move_by(directions.0).unwrap();
-                came_from = -directions.0;
-            }
+                return None;
+            };
+            current = current.move_by(next_direction)?;
+            came_from = -next_direction;
         }
         loop_points.push(start);
 
-        loop_points
+        Some(loop_points)
     }
 
-    pub fn identify_start_pipe(&self, start: spatial::UPoint2D) -> Pipe {
+    // None unless exactly two pipes connect to the start.
+    pub fn identify_start_pipe(&self, start: spatial::UPoint2D) -> Option<Pipe> {
         let connected: Vec<_> = start
             .neighbours()
             .iter()
@@ -63,24 +120,159 @@ impl Field {
             })
             .collect();
 
-        // The resulting Vec should contain exactly two elements for the given input.
-        (
-            cmp::min(connected[0], connected[1]), // for the sake of consistency, return them ordered
-            cmp::max(connected[0], connected[1]),
-        )
+        match connected[..] {
+            [first, second] => Some((
+                cmp::min(first, second), // for the sake of consistency, return them ordered
+                cmp::max(first, second),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl ClosedLoop {
+    fn trace_for(field: &Field, start: spatial::UPoint2D) -> Result<Self> {
+        let start_pipe = field
+            .identify_start_pipe(start)
+            .ok_or_else(|| anyhow!("the start is not connected to exactly two pipes"))?;
+        let path = field
+            .trace_loop(start, start_pipe.0)
+            .ok_or_else(|| anyhow!("the pipes from the start do not form a loop"))?;
+        let pipes = path
+            .iter()
+            .map(|&point| {
+                if point == start {
+                    (point, start_pipe)
+                } else {
+                    (point, field.pipes[&point])
+                }
+            })
+            .collect();
+        Ok(Self { path, pipes })
+    }
+
+    fn area(&self) -> u64 {
+        let by_rows = self.by_rows();
+
+        by_rows
+            .iter()
+            .map(|(&row, ranges)| self.row_area(row, ranges))
+            .sum::<usize>() as u64
+    }
+
+    fn by_rows(&self) -> HashMap<usize, Vec<Range>> {
+        let mut path = self.path.clone(); // TODO
+        path.sort_unstable();
+        let mut by_rows: HashMap<usize, Vec<Range>> = HashMap::new();
+        let mut idx = 0;
+
+        while idx < path.len() {
+            let spatial::UPoint2D { row, column } = path[idx];
+            let start_idx = idx;
+            while idx < path.len()
+                && path[idx].row == row
+                && continues_rightward(self.pipes[&path[idx]])
+            {
+                idx += 1;
+            }
+            idx += 1;
+            by_rows.entry(row).or_default().push(Range {
+                start: column,
+                size: idx - start_idx,
+            });
+        }
+
+        by_rows
+            .iter_mut()
+            .for_each(|(_, ranges)| ranges.sort_unstable_by_key(|range| range.start));
+
+        by_rows
+    }
+
+    fn row_area(&self, row: usize, ranges: &[Range]) -> usize {
+        let pipe_at = |column| self.pipes[&spatial::UPoint2D { row, column }];
+        let mut inside = false;
+        let mut accumulated_miles: usize = 0;
+
+        for idx in 0..ranges.len() {
+            if inside {
+                accumulated_miles += ranges[idx].start - ranges[idx - 1].end();
+            }
+            if ranges[idx].size > 1 {
+                if continues_rightward(pipe_at(ranges[idx].start)) {
+                    inside = self.update_inside(inside, row, &ranges[idx]);
+                }
+            } else {
+                inside = !inside;
+            }
+        }
+        accumulated_miles
+    }
+
+    fn update_inside(&self, current_inside: bool, row: usize, range: &Range) -> bool {
+        // When we run into a horizontal wall, we need to decide whether
+        // we are inside or outside the loop after it. This depends on the
+        // current value of inside and the direction the wall came from
+        // and continues towards.
+        //
+        // For instance, if we find this wall (looking only at the line in the middle)
+        //
+        // IIIIIII | OO
+        // II F----J OO
+        // II | OOOOOOO
+        //
+        // If the left end is inside the loop, then the right end is outside
+        // because the wall came from below but continues upwards, so we invert
+        // its value.
+        //
+        // If on the other hand, the left and right ends of the wall come
+        // from the same direction, then inside is unchanged, e.g.
+        //
+        // IIIIIIIIIIII
+        // II F----7 II
+        // II | OO | II
+        //
+        // or
+        //
+        // OO | II | OO
+        // OO F----7 OO
+        // OOOOOOOOOOOO
+        let left_end = self.pipes[&spatial::UPoint2D {
+            row,
+            column: range.start,
+        }];
+        let right_end = self.pipes[&spatial::UPoint2D {
+            row,
+            column: range.end() - 1,
+        }];
+        current_inside == (came_from(left_end) == came_from(right_end))
+    }
+}
+
+impl Range {
+    fn end(&self) -> usize {
+        self.start + self.size
     }
 }
 
+fn came_from(pipe: Pipe) -> spatial::Direction {
+    pipe.0
+}
+
+fn continues_rightward(pipe: Pipe) -> bool {
+    pipe.1 == spatial::Direction::Right
+}
+
 impl RawPipe {
-    fn parse(input: char) -> Result<RawPipe> {
+    fn parse(input: char) -> Option<RawPipe> {
         match input {
-            '-' => Ok(RawPipe::Horizontal),
-            '|' => Ok(RawPipe::Vertical),
-            'J' => Ok(RawPipe::LeftTop),
-            'L' => Ok(RawPipe::RightTop),
-            '7' => Ok(RawPipe::LeftBottom),
-            'F' => Ok(RawPipe::RightBottom),
-            _ => Err(anyhow!("Invalid pipe: {}", input)),
+            '-' => Some(RawPipe::Horizontal),
+            '|' => Some(RawPipe::Vertical),
+            'J' => Some(RawPipe::LeftTop),
+            'L' => Some(RawPipe::RightTop),
+            '7' => Some(RawPipe::LeftBottom),
+            'F' => Some(RawPipe::RightBottom),
+            _ => None,
         }
     }
 
@@ -96,39 +288,26 @@ impl RawPipe {
     }
 }
 
-pub fn parse_input(input: &str) -> (Field, spatial::UPoint2D) {
-    let mut pipes = vec![];
-    let mut start = None;
-
-    for (row, o_start) in input.lines().enumerate().map(parse_line) {
-        pipes.extend(row.into_iter());
-        start = start.or(o_start);
-    }
-    (
-        Field {
-            pipes: pipes.into_iter().collect(),
-        },
-        start.unwrap(),
-    )
-}
-
-fn parse_line(
-    (row, line): (usize, &str),
-) -> (Vec<(spatial::UPoint2D, Pipe)>, Option<spatial::UPoint2D>) {
-    let mut pipes = vec![];
-    let mut start = None;
-    for (column, ch) in line.chars().enumerate() {
-        if ch == 'S' {
-            start = Some(spatial::UPoint2D::from(row, column));
-        } else if ch == '.' {
-            continue;
-        } else {
-            pipes.push((
-                spatial::UPoint2D::from(row, column),
-                RawPipe::parse(ch).unwrap().directions(),
-            ));
-        }
-    }
+pub fn parse_input(input: &str) -> ParseResult<(Field, spatial::UPoint2D)> {
+    let tiles = Grid::parse(input, |ch| match ch {
+        '.' => Some(Tile::Ground),
+        'S' => Some(Tile::Start),
+        _ => RawPipe::parse(ch).map(|raw_pipe| Tile::Pipe(raw_pipe.directions())),
+    })?;
+    let start = tiles
+        .iter()
+        .find(|(_, tile)| matches!(tile, Tile::Start))
+        .map(|(position, _)| position)
+        .ok_or_else(|| {
+            ParseError::end_of_input(input, ParseErrorKind::Expected("a starting position 'S'"))
+        })?;
+    let pipes = tiles
+        .iter()
+        .filter_map(|(position, tile)| match tile {
+            Tile::Pipe(pipe) => Some((position, *pipe)),
+            _ => None,
+        })
+        .collect();
 
-    (pipes, start)
+    Ok((Field { pipes }, start))
 }
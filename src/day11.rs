@@ -1,7 +1,11 @@
+use crate::common::Grid;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use crate::spatial::UPoint2D;
+use anyhow::Result;
 use std::cmp;
 
-type Distance = u64;
+pub type Distance = u64;
 type Position = usize;
 type Galaxy = UPoint2D;
 
@@ -12,6 +16,25 @@ pub struct Image {
 
 type Axis = Vec<Position>;
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Puzzle = Image;
+    type Answer = Distance;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(image: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(image.total_distance(2))
+    }
+
+    fn part_two(image: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(image.total_distance(1000000))
+    }
+}
+
 impl Image {
     pub fn total_distance(&self, dark_energy: Distance) -> Distance {
         let rows = self.rows();
@@ -36,8 +59,8 @@ impl Image {
         columns: &Axis,
         dark_energy: Distance,
     ) -> Distance {
-        (from.row as isize - to.row as isize).abs() as u64
-            + (from.column as isize - to.column as isize).abs() as u64
+        from.row.abs_diff(to.row) as u64
+            + from.column.abs_diff(to.column) as u64
             + (dark_energy - 1)
                 * Image::count_empty(cmp::min(from.row, to.row), cmp::max(from.row, to.row), rows)
             + (dark_energy - 1)
@@ -95,19 +118,17 @@ impl Image {
     }
 }
 
-pub fn parse_input(input: &str) -> Image {
-    let galaxies = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row, line)| {
-            line.chars().enumerate().filter_map(move |(column, char)| {
-                if char == '#' {
-                    Some(UPoint2D::from(row, column))
-                } else {
-                    None
-                }
-            })
-        })
+pub fn parse_input(input: &str) -> ParseResult<Image> {
+    let image = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let galaxies = image
+        .iter()
+        .filter(|&(_, &is_galaxy)| is_galaxy)
+        .map(|(position, _)| position)
         .collect();
-    Image { galaxies }
+
+    Ok(Image { galaxies })
 }
@@ -1,3 +1,8 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
 use std::cmp;
 use std::collections::BTreeMap;
 use std::iter;
@@ -17,6 +22,31 @@ struct Arrangement<'a> {
 type SpringT = u8;
 struct Spring;
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Puzzle = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(records: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(records
+            .iter()
+            .map(|record| record.count_arrangements())
+            .sum())
+    }
+
+    fn part_two(records: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(records
+            .iter()
+            .map(|record| record.unfold().count_arrangements())
+            .sum())
+    }
+}
+
 impl Record {
     pub fn count_arrangements(&self) -> usize {
         let self_arrangement = Arrangement::new(self.row.as_bytes(), &self.lengths);
@@ -49,11 +79,20 @@ impl Record {
         Record { row, lengths }
     }
 
-    pub fn parse(line: &str) -> Self {
-        let (raw_row, raw_lengths) = line.split_once(' ').unwrap();
+    pub fn parse(line: Line) -> ParseResult<Self> {
+        let (raw_row, raw_lengths) = line.split_once(line.text, " ")?;
+        if let Some((offset, spring)) = line
+            .char_indices(raw_row)
+            .find(|&(_, spring)| !(spring.is_ascii() && Spring::is_valid(spring as u8)))
+        {
+            return Err(line.unexpected_character(offset, spring));
+        }
         let row = raw_row.to_string();
-        let lengths: Vec<usize> = raw_lengths.split(',').flat_map(|s| s.parse()).collect();
-        Self { row, lengths }
+        let lengths: Vec<usize> = raw_lengths
+            .split(',')
+            .map(|raw_length| line.number(raw_length))
+            .collect::<ParseResult<_>>()?;
+        Ok(Self { row, lengths })
     }
 }
 
@@ -77,7 +116,7 @@ impl<'a> Arrangement<'a> {
 
         let unknown_prefix_dropped = iter::once(Arrangement::new(
             &self.row[unknown_prefix_length..],
-            &self.lengths,
+            self.lengths,
         ))
         // We only want to drop the unknown prefix if it is non-empty, so we are making progress,
         // *and* we can skip a few operational springs and try to apply the block in the next section.
@@ -103,9 +142,9 @@ impl<'a> Arrangement<'a> {
             })
             .map(|potential_end| {
                 if potential_end == self.row.len() {
-                    Arrangement::new(&self.row[potential_end..], tail(&self.lengths))
+                    Arrangement::new(&self.row[potential_end..], tail(self.lengths))
                 } else {
-                    Arrangement::new(&self.row[potential_end + 1..], tail(&self.lengths))
+                    Arrangement::new(&self.row[potential_end + 1..], tail(self.lengths))
                 }
             });
 
@@ -114,23 +153,16 @@ impl<'a> Arrangement<'a> {
 
     fn is_satisfiable(&self) -> Option<bool> {
         let total_expected_damaged = self.lengths.iter().sum::<usize>();
+        let potentially_damaged = self
+            .row
+            .iter()
+            .filter(|&&s| Spring::is_potentially_damaged(s))
+            .count();
+        let damaged = self.row.iter().filter(|&&s| Spring::is_damaged(s)).count();
+
         if self.lengths.is_empty() {
-            Some(!self.row.into_iter().any(|&s| Spring::is_damaged(s)))
-        } else if total_expected_damaged
-            > self
-                .row
-                .iter()
-                .filter(|&&s| Spring::is_potentially_damaged(s))
-                .count()
-        {
-            Some(false)
-        } else if total_expected_damaged
-            < self
-                .row
-                .into_iter()
-                .filter(|&&s| Spring::is_damaged(s))
-                .count()
-        {
+            Some(damaged == 0)
+        } else if total_expected_damaged > potentially_damaged || total_expected_damaged < damaged {
             Some(false)
         } else {
             None
@@ -143,10 +175,10 @@ impl<'a> Arrangement<'a> {
             idx += 1;
         }
 
-        return Self {
+        Self {
             row: &self.row[idx..],
-            lengths: &self.lengths,
-        };
+            lengths: self.lengths,
+        }
     }
 }
 
@@ -162,6 +194,10 @@ impl Ord for Arrangement<'_> {
 }
 
 impl Spring {
+    fn is_valid(spring: SpringT) -> bool {
+        Spring::is_potentially_damaged(spring) || Spring::is_operational(spring)
+    }
+
     fn is_potentially_damaged(spring: SpringT) -> bool {
         Spring::is_damaged(spring) || Spring::is_unknown(spring)
     }
@@ -183,8 +219,8 @@ impl Spring {
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Record> {
-    input.lines().map(Record::parse).collect()
+pub fn parse_input(input: &str) -> ParseResult<Vec<Record>> {
+    parse::lines(input).map(Record::parse).collect()
 }
 
 fn tail<T>(list: &[T]) -> &[T] {
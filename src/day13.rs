@@ -1,3 +1,11 @@
+use crate::common::Grid;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::anyhow;
+use anyhow::Result;
+
 type AxisSummary = u32;
 #[derive(Debug)]
 pub struct Pattern {
@@ -11,33 +19,61 @@ pub enum Reflection {
     Vertical(usize),
 }
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Puzzle = Vec<Pattern>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(patterns: &Self::Puzzle) -> Result<Self::Answer> {
+        summarize_all(patterns, Pattern::find_reflection)
+    }
+
+    fn part_two(patterns: &Self::Puzzle) -> Result<Self::Answer> {
+        summarize_all(patterns, Pattern::find_smudged_reflection)
+    }
+}
+
+fn summarize_all(
+    patterns: &[Pattern],
+    find: impl Fn(&Pattern) -> Option<Reflection>,
+) -> Result<usize> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(idx, pattern)| {
+            find(pattern)
+                .map(|reflection| reflection.summarize())
+                .ok_or_else(|| anyhow!("pattern {} has no line of reflection", idx + 1))
+        })
+        .sum()
+}
+
 impl Pattern {
-    pub fn find_smudged_reflection(&self) -> Reflection {
+    pub fn find_smudged_reflection(&self) -> Option<Reflection> {
         let horizontal_candidates = (1..self.rows.len())
             .filter(|&split_at| Self::is_reflected_with_smudge_at(&self.rows, split_at))
-            .map(|split_at| Reflection::Horizontal(split_at));
+            .map(Reflection::Horizontal);
         let vertical_candidates = (1..self.columns.len())
             .filter(|&split_at| Self::is_reflected_with_smudge_at(&self.columns, split_at))
-            .map(|split_at| Reflection::Vertical(split_at));
+            .map(Reflection::Vertical);
 
-        horizontal_candidates
-            .chain(vertical_candidates)
-            .next()
-            .unwrap()
+        horizontal_candidates.chain(vertical_candidates).next()
     }
 
-    pub fn find_reflection(&self) -> Reflection {
+    pub fn find_reflection(&self) -> Option<Reflection> {
         let horizontal_candidates = (1..self.rows.len())
             .filter(|&split_at| Self::is_reflected_at(&self.rows, split_at))
-            .map(|split_at| Reflection::Horizontal(split_at));
+            .map(Reflection::Horizontal);
         let vertical_candidates = (1..self.columns.len())
             .filter(|&split_at| Self::is_reflected_at(&self.columns, split_at))
-            .map(|split_at| Reflection::Vertical(split_at));
+            .map(Reflection::Vertical);
 
-        horizontal_candidates
-            .chain(vertical_candidates)
-            .next()
-            .unwrap()
+        horizontal_candidates.chain(vertical_candidates).next()
     }
 
     fn is_reflected_with_smudge_at(axis: &[AxisSummary], index: usize) -> bool {
@@ -78,44 +114,29 @@ impl Pattern {
             .all(|(left, right)| left == right)
     }
 
-    fn parse(input: &str) -> Self {
-        let rows = Self::parse_rows(input);
-        let columns = Self::parse_columns(input);
-        Self { rows, columns }
-    }
-
-    fn parse_rows(input: &str) -> Vec<AxisSummary> {
-        input
-            .lines()
-            .map(str::chars)
-            .map(Self::parse_axis_summary)
-            .collect()
-    }
-
-    fn parse_columns(input: &str) -> Vec<AxisSummary> {
-        // Build a Vec of iterators over the characters of each line
-        let mut line_iterators: Vec<_> = input.lines().map(str::chars).collect();
-        let columns_count = input.lines().next().unwrap().len();
-        (0..columns_count)
-            .map(|_| {
-                // Build an iterator such that each element is the next character of the corresponding
-                // line iterator, which is consumed in the process, and apply parse_axis_summary to it.
-                // This way we first parse the first character of each line, which is the first column,
-                // then the second one, and so on. It's a convoluted way of transposing the input.
-                Self::parse_axis_summary(line_iterators.iter_mut().map(|line| line.next().unwrap()))
-            })
-            .collect()
+    fn parse(block: &[Line]) -> ParseResult<Self> {
+        let bits = Grid::parse_lines(block, Self::parse_bit)?;
+
+        let rows = bits
+            .rows()
+            .map(|row| Self::summarize_axis(row.iter().copied()))
+            .collect();
+        let columns = bits
+            .columns()
+            .map(|column| Self::summarize_axis(column.copied()))
+            .collect();
+        Ok(Self { rows, columns })
     }
 
-    fn parse_axis_summary(axis: impl Iterator<Item = char>) -> AxisSummary {
-        axis.fold(0, |acc, c| (acc << 1) | Self::parse_bit(c))
+    fn summarize_axis(axis: impl Iterator<Item = AxisSummary>) -> AxisSummary {
+        axis.fold(0, |acc, bit| (acc << 1) | bit)
     }
 
-    fn parse_bit(input: char) -> AxisSummary {
+    fn parse_bit(input: char) -> Option<AxisSummary> {
         match input {
-            '#' => 1,
-            '.' => 0,
-            _ => panic!("Invalid character"),
+            '#' => Some(1),
+            '.' => Some(0),
+            _ => None,
         }
     }
 }
@@ -129,6 +150,9 @@ impl Reflection {
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Pattern> {
-    input.split("\n\n").map(Pattern::parse).collect()
+pub fn parse_input(input: &str) -> ParseResult<Vec<Pattern>> {
+    parse::blocks(input)
+        .iter()
+        .map(|block| Pattern::parse(block))
+        .collect()
 }
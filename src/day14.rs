@@ -1,51 +1,90 @@
-use crate::common::CycleDetection;
-use crate::common::FiniteCycleIter;
+use crate::common::nth_with_cycle_skip;
+use crate::common::Grid;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use crate::spatial::Direction;
+use anyhow::Result;
 use std::iter::once;
 use std::iter::zip;
 use std::ops::Range;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub struct Platform {
     cubed: Cubed,
     rounded: Rounded,
 }
 
+// Yields the platform before each spin cycle.
 pub struct PlatformIter {
     platform: Platform,
-    direction: FiniteCycleIter<Direction>,
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 struct Cubed {
     rows: Vec<CubedAxis>,
     columns: Vec<CubedAxis>,
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 struct Rounded {
     rows: Vec<RoundedAxis>,
     columns: Vec<RoundedAxis>,
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Rock {
+    Rounded,
+    Cubed,
+}
+
 type RoundedAxis = Vec<Position>;
 type CubedAxis = Vec<Position>;
 
 type Position = usize;
 type Span = fn(Position, Position, usize) -> Range<Position>;
 
+// North, then west, then south, then east.
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Puzzle = Platform;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(platform: &Self::Puzzle) -> Result<Self::Answer> {
+        let mut platform = platform.clone();
+        platform.tilt(Direction::Up);
+        Ok(platform.load())
+    }
+
+    fn part_two(platform: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(platform.clone().run_for(1000000000).load())
+    }
+}
+
 impl Platform {
-    pub fn run_for(self, tilts_count: usize) -> Platform {
-        let cycle = self.detect_cycle();
-
-        if tilts_count < cycle.mu {
-            self.into_iter().skip(tilts_count).next().unwrap()
-        } else {
-            self.into_iter()
-                .skip(cycle.mu)
-                .skip((tilts_count - cycle.mu) % cycle.lambda)
-                .next()
-                .unwrap()
+    // The same board comes up between different tilts of a spin cycle, so
+    // only whole spin cycles are compared when looking for a repeat.
+    pub fn run_for(self, spins_count: u64) -> Platform {
+        nth_with_cycle_skip(self, spins_count).expect("spinning never ends")
+    }
+
+    pub fn spin(&mut self) {
+        for direction in SPIN_CYCLE {
+            self.tilt(direction);
         }
     }
 
@@ -68,7 +107,7 @@ impl Platform {
     }
 
     fn apply_tilt(&mut self, tilt_by: fn(&Self, Span) -> Rounded, span: Span) {
-        self.rounded = tilt_by(&self, span);
+        self.rounded = tilt_by(self, span);
     }
 
     fn tilt_by_rows(&self, span: Span) -> Rounded {
@@ -103,7 +142,7 @@ impl Platform {
         cubed
             .iter()
             .zip(rounded.iter())
-            .map(|(cubed, rounded)| self.tilt_axis(&cubed, &rounded, axis_length, span))
+            .map(|(cubed, rounded)| self.tilt_axis(cubed, rounded, axis_length, span))
             .collect()
     }
 
@@ -160,18 +199,7 @@ impl IntoIterator for Platform {
     type Item = Platform;
     type IntoIter = PlatformIter;
     fn into_iter(self) -> Self::IntoIter {
-        PlatformIter {
-            platform: self,
-            direction: FiniteCycleIter {
-                values: vec![
-                    Direction::Up,
-                    Direction::Left,
-                    Direction::Down,
-                    Direction::Right,
-                ],
-                index: 0,
-            },
-        }
+        PlatformIter { platform: self }
     }
 }
 
@@ -179,14 +207,13 @@ impl Iterator for PlatformIter {
     type Item = Platform;
     fn next(&mut self) -> Option<Self::Item> {
         let current = self.platform.clone();
-        let direction = self.direction.next();
-        self.platform.tilt(direction?);
+        self.platform.spin();
         Some(current)
     }
 }
 
 impl Cubed {
-    fn transpose(axes: &Vec<CubedAxis>) -> Vec<CubedAxis> {
+    fn transpose(axes: &[CubedAxis]) -> Vec<CubedAxis> {
         let mut transposed = vec![vec![]; axes.len()]; // Assume same length
         for (row, axis) in axes.iter().enumerate() {
             for &position in axis.iter() {
@@ -199,7 +226,7 @@ impl Cubed {
 }
 
 impl Rounded {
-    fn transpose(axes: &Vec<RoundedAxis>) -> Vec<RoundedAxis> {
+    fn transpose(axes: &[RoundedAxis]) -> Vec<RoundedAxis> {
         let mut transposed = vec![vec![]; axes.len()]; // Assume same length
         for (row, axis) in axes.iter().enumerate() {
             for &position in axis.iter() {
@@ -211,12 +238,39 @@ impl Rounded {
     }
 }
 
-pub fn parse_input(input: &str) -> Platform {
-    let (rows_cubed, rows_rounded) = input.lines().map(parse_row).unzip();
+pub fn parse_input(input: &str) -> ParseResult<Platform> {
+    let lines: Vec<_> = parse::lines(input).collect();
+    // Tilting and transposing rely on the platform being square.
+    let width = parse::grid_width(&lines)?;
+    if let Some(extra_row) = lines.get(width) {
+        return Err(extra_row.error_at(
+            extra_row.text,
+            ParseErrorKind::Expected("a square platform"),
+        ));
+    } else if lines.len() < width {
+        return Err(ParseError::end_of_input(
+            input,
+            ParseErrorKind::Expected("a square platform"),
+        ));
+    }
+    let rocks = Grid::parse_lines(&lines, |c| match c {
+        'O' => Some(Some(Rock::Rounded)),
+        '#' => Some(Some(Rock::Cubed)),
+        '.' => Some(None),
+        _ => None,
+    })?;
+    let rows_cubed: Vec<CubedAxis> = rocks
+        .rows()
+        .map(|row| positions(row, Rock::Cubed))
+        .collect();
+    let rows_rounded: Vec<RoundedAxis> = rocks
+        .rows()
+        .map(|row| positions(row, Rock::Rounded))
+        .collect();
     let columns_cubed = Cubed::transpose(&rows_cubed);
     let columns_rounded = Rounded::transpose(&rows_rounded);
 
-    Platform {
+    Ok(Platform {
         cubed: Cubed {
             rows: rows_cubed,
             columns: columns_cubed,
@@ -225,23 +279,13 @@ pub fn parse_input(input: &str) -> Platform {
             rows: rows_rounded,
             columns: columns_rounded,
         },
-    }
+    })
 }
 
-fn parse_row(line: &str) -> (CubedAxis, RoundedAxis) {
-    line.chars().enumerate().fold(
-        (vec![], vec![]),
-        |(mut cubed, mut rounded), (idx, c)| match c {
-            'O' => {
-                rounded.push(idx);
-                (cubed, rounded)
-            }
-            '#' => {
-                cubed.push(idx);
-                (cubed, rounded)
-            }
-            '.' => (cubed, rounded),
-            _ => panic!("Invalid character"),
-        },
-    )
+fn positions(row: &[Option<Rock>], kind: Rock) -> Vec<Position> {
+    row.iter()
+        .enumerate()
+        .filter(|&(_, &rock)| rock == Some(kind))
+        .map(|(idx, _)| idx)
+        .collect()
 }
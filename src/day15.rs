@@ -1,4 +1,63 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt;
+
 pub type HashResult = u8;
+type Box = u8;
+type Lens = u8;
+type Label = String;
+pub type FocusingPower = u64;
+pub enum Step {
+    Remove(RemoveInfo),
+    Put(PutInfo),
+}
+pub struct RemoveInfo {
+    label: Label,
+}
+pub struct PutInfo {
+    label: Label,
+    focal_length: Lens,
+}
+//#[derive(Debug)]
+struct LensConfiguration {
+    box_to_lenses: BoxToLenses,
+    lens_to_box: LensToBox,
+}
+type BoxToLenses = HashMap<Box, Vec<(Label, Lens)>>;
+type LensToBox = HashMap<Lens, Box>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Puzzle = Vec<Step>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    // The first part hashes each step exactly as it was written down.
+    fn part_one(steps: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(steps
+            .iter()
+            .map(|step| hash(&step.to_string()) as u64)
+            .sum())
+    }
+
+    fn part_two(steps: &Self::Puzzle) -> Result<Self::Answer> {
+        let mut lens_configuration = LensConfiguration {
+            box_to_lenses: HashMap::new(),
+            lens_to_box: HashMap::new(),
+        };
+        lens_configuration.apply(steps);
+        Ok(lens_configuration.focusing_power())
+    }
+}
 
 pub fn hash(label: &str) -> HashResult {
     label.chars().fold(0, |acc, x| {
@@ -8,3 +67,148 @@ pub fn hash(label: &str) -> HashResult {
     })
     //step.chars().fold(0, |acc, x| ((acc + x as u64) * 17) % 256)
 }
+
+impl LensConfiguration {
+    fn focusing_power(self) -> FocusingPower {
+        self.box_to_lenses
+            .values()
+            .map(|label_to_lens| {
+                label_to_lens
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, (label, focal_length))| {
+                        let target_box = hash(label);
+                        (1 + target_box as FocusingPower)
+                            * (1 + idx as FocusingPower)
+                            * *focal_length as FocusingPower
+                    })
+                    .sum::<FocusingPower>()
+            })
+            .sum()
+    }
+
+    fn apply(&mut self, steps: &[Step]) {
+        for step in steps {
+            match step {
+                Step::Remove(r) => self.remove(r),
+                Step::Put(p) => self.put(p),
+            };
+        }
+    }
+
+    fn remove(&mut self, remove_info: &RemoveInfo) {
+        let target_box = hash(&remove_info.label);
+
+        // First find which focal length (if any) it currently refers to and unassign it
+        if let Some(label_to_lens) = self.box_to_lenses.get(&target_box) {
+            label_to_lens
+                .iter()
+                .find(|(label, _)| label == &remove_info.label)
+                .and_then(|(_, focal_length)| self.lens_to_box.remove(focal_length));
+        }
+
+        // Then remove the entry from the box
+        if let Some(label_to_lens) = self.box_to_lenses.get_mut(&target_box) {
+            if let Some((idx, _)) = label_to_lens
+                .iter()
+                .enumerate()
+                .find(|(_, (label, _))| label == &remove_info.label)
+            {
+                label_to_lens.remove(idx);
+            }
+        }
+    }
+
+    fn put(&mut self, put_info: &PutInfo) {
+        let target_box = hash(&put_info.label);
+
+        // First find which focal length (if any) it currently refers to and unassign it
+        self.box_to_lenses
+            .entry(target_box)
+            .and_modify(|label_to_lens| {
+                if let Some((_, focal_length)) = label_to_lens
+                    .iter()
+                    .find(|(label, _)| label == &put_info.label)
+                {
+                    self.lens_to_box.remove(focal_length);
+                    self.lens_to_box.insert(*focal_length, target_box);
+                }
+            });
+
+        // Then add the entry to the box
+        self.box_to_lenses
+            .entry(target_box)
+            .and_modify(|label_to_lens| {
+                if let Some((idx, _)) = label_to_lens
+                    .iter()
+                    .enumerate()
+                    .find(|(_, (label, _))| label == &put_info.label)
+                {
+                    label_to_lens[idx].1 = put_info.focal_length;
+                } else {
+                    label_to_lens.push((put_info.label.to_string(), put_info.focal_length));
+                }
+            })
+            .or_insert(vec![(put_info.label.to_string(), put_info.focal_length)]);
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<Step>> {
+    let mut steps = vec![];
+
+    for line in parse::lines(input) {
+        for raw_step in line.text.split(',') {
+            steps.push(Step::parse(line, raw_step.trim())?);
+        }
+    }
+
+    Ok(steps)
+}
+
+impl Step {
+    fn parse(line: Line, raw_step: &str) -> ParseResult<Step> {
+        let label_length = raw_step
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(raw_step.len());
+        let (label, operation) = raw_step.split_at(label_length);
+        if label.is_empty() {
+            return Err(line.error_at(raw_step, ParseErrorKind::Expected("a label")));
+        }
+        let label = label.to_string();
+        let mut operation = line.char_indices(operation);
+
+        let step = match operation.next() {
+            Some((_, '-')) => Step::Remove(RemoveInfo { label }),
+            Some((_, '=')) => match operation.next() {
+                Some((offset, f)) => Step::Put(PutInfo {
+                    label,
+                    focal_length: f
+                        .to_digit(10)
+                        .ok_or_else(|| line.unexpected_character(offset, f))?
+                        as Lens,
+                }),
+                None => {
+                    return Err(
+                        line.error_after(raw_step, ParseErrorKind::Expected("a focal length"))
+                    )
+                }
+            },
+            Some((offset, c)) => return Err(line.unexpected_character(offset, c)),
+            None => return Err(line.error_after(raw_step, ParseErrorKind::Expected("'-' or '='"))),
+        };
+
+        match operation.next() {
+            Some((offset, c)) => Err(line.unexpected_character(offset, c)),
+            None => Ok(step),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Remove(remove_info) => write!(f, "{}-", remove_info.label),
+            Step::Put(put_info) => write!(f, "{}={}", put_info.label, put_info.focal_length),
+        }
+    }
+}
@@ -1,9 +1,16 @@
+use crate::common::Grid;
+use crate::graph::Traversal;
+use crate::graph::UnweightedGraph;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use crate::spatial::Direction;
 use crate::spatial::UPoint2D;
+use anyhow::Result;
 use either::Either;
 use either::Left;
 use either::Right;
-use std::collections::HashMap;
 use std::collections::HashSet;
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -15,9 +22,7 @@ pub enum Mirror {
 }
 
 pub struct Contraption {
-    mirrors: HashMap<UPoint2D, Mirror>,
-    width: usize,
-    height: usize,
+    mirrors: Grid<Option<Mirror>>,
 }
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
@@ -26,22 +31,45 @@ pub struct Beam {
     pub direction: Direction,
 }
 
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Puzzle = Contraption;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(Contraption::parse(input)?)
+    }
+
+    fn part_one(contraption: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(contraption.count_energized_tiles(Beam {
+            position: UPoint2D::from(0, 0),
+            direction: Direction::Right,
+        }))
+    }
+
+    fn part_two(contraption: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(contraption.maximize_energized_tiles())
+    }
+}
+
 impl Contraption {
     pub fn maximize_energized_tiles(&self) -> usize {
-        let top = (0..self.width).map(|column| Beam {
+        let (width, height) = (self.mirrors.get_width(), self.mirrors.get_height());
+        let top = (0..width).map(|column| Beam {
             position: UPoint2D::from(0, column),
             direction: Direction::Down,
         });
-        let bottom = (0..self.width).map(|column| Beam {
-            position: UPoint2D::from(self.height - 1, column),
+        let bottom = (0..width).map(|column| Beam {
+            position: UPoint2D::from(height - 1, column),
             direction: Direction::Up,
         });
-        let left = (0..self.height).map(|row| Beam {
+        let left = (0..height).map(|row| Beam {
             position: UPoint2D::from(row, 0),
             direction: Direction::Right,
         });
-        let right = (0..self.height).map(|row| Beam {
-            position: UPoint2D::from(row, self.width - 1),
+        let right = (0..height).map(|row| Beam {
+            position: UPoint2D::from(row, width - 1),
             direction: Direction::Left,
         });
 
@@ -57,63 +85,47 @@ impl Contraption {
         self.beam_path(seed).len()
     }
 
-    pub fn parse(input: &str) -> Self {
-        let mirrors = input
-            .lines()
-            .enumerate()
-            .flat_map(|(row, line)| {
-                line.char_indices()
-                    .map(move |(column, tile)| ((row, column), tile))
-            })
-            .filter(|(_, tile)| tile != &'.')
-            .map(|((row, column), tile)| (UPoint2D::from(row, column), Mirror::parse(tile)))
-            .collect();
-
-        let width = input.lines().next().unwrap().len();
-        let height = input.lines().count();
-
-        Self {
-            mirrors,
-            width,
-            height,
+    pub fn parse(input: &str) -> ParseResult<Self> {
+        let mirrors = Grid::parse(input, |tile| match tile {
+            '.' => Some(None),
+            _ => Mirror::parse(tile).map(Some),
+        })?;
+        if mirrors.get_width() == 0 {
+            return Err(ParseError::end_of_input(
+                input,
+                ParseErrorKind::Expected("at least one tile"),
+            ));
         }
+
+        Ok(Self { mirrors })
     }
 
     fn beam_path(&self, seed: Beam) -> HashSet<UPoint2D> {
-        let mut visited = HashSet::new();
-        let mut queue = vec![seed];
-
-        while let Some(beam) = queue.pop() {
-            if visited.contains(&beam) {
-                continue;
-            }
-
-            visited.insert(beam);
-
-            let next_direction = self
-                .mirrors
-                .get(&beam.position)
-                .map(|mirror| mirror.reflect(beam.direction))
-                .unwrap_or(Left(beam.direction));
-
-            let directions = match next_direction {
-                Left(d) => vec![d],
-                Right((d1, d2)) => vec![d1, d2],
-            };
-
-            queue.extend(
-                directions
-                    .into_iter()
-                    .flat_map(|d| beam.advance(d).into_iter())
-                    .filter(|beam| self.is_valid(beam.position)),
-            );
-        }
-
-        visited.iter().map(|beam| beam.position).collect()
+        self.reachable(&[seed])
+            .into_iter()
+            .map(|beam| beam.position)
+            .collect()
     }
+}
 
-    fn is_valid(&self, position: UPoint2D) -> bool {
-        position.within_bounds(self.width, self.height)
+impl UnweightedGraph<Beam> for Contraption {
+    fn successors(&self, beam: &Beam) -> Vec<Beam> {
+        let next_direction = self
+            .mirrors
+            .at(beam.position)
+            .map(|mirror| mirror.reflect(beam.direction))
+            .unwrap_or(Left(beam.direction));
+
+        let directions = match next_direction {
+            Left(d) => vec![d],
+            Right((d1, d2)) => vec![d1, d2],
+        };
+
+        directions
+            .into_iter()
+            .flat_map(|d| beam.advance(d).into_iter())
+            .filter(|beam| self.mirrors.contains(beam.position))
+            .collect()
     }
 }
 
@@ -127,13 +139,13 @@ impl Beam {
 }
 
 impl Mirror {
-    fn parse(tile: char) -> Mirror {
+    fn parse(tile: char) -> Option<Mirror> {
         match tile {
-            '-' => Mirror::Horizontal,
-            '|' => Mirror::Vertical,
-            '\\' => Mirror::Diagonal,
-            '/' => Mirror::Antidiagonal,
-            _ => panic!("Unexpected tile: {}", tile),
+            '-' => Some(Mirror::Horizontal),
+            '|' => Some(Mirror::Vertical),
+            '\\' => Some(Mirror::Diagonal),
+            '/' => Some(Mirror::Antidiagonal),
+            _ => None,
         }
     }
 
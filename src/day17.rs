@@ -1,22 +1,127 @@
-use crate::common::Matrix;
+use crate::common::Grid;
 use crate::graph;
+use crate::graph::Dial;
+use crate::graph::Dijkstra;
+use crate::graph::Path;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use crate::spatial::Direction;
 use crate::spatial::Orientation;
 use crate::spatial::UPoint2D;
+use anyhow::anyhow;
+use anyhow::Result;
 
 pub type HeatLoss = u64;
 
-pub struct BaseCityMap(pub Matrix<HeatLoss>);
+// The most blocks either crucible can move in a straight line.
+const MAX_CONSECUTIVE: usize = 10;
+
+pub struct BaseCityMap(pub Grid<HeatLoss>);
+
+pub struct RegularCityMap<'a>(pub &'a BaseCityMap);
+
+pub struct UltracrucibleCityMap<'a>(pub &'a BaseCityMap);
+
+// Moves between neighbouring blocks with no turning rules, walked backwards:
+// each edge costs the heat lost entering the block it comes from.
+struct FreeCityMap<'a>(&'a BaseCityMap);
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Puzzle = BaseCityMap;
+    type Answer = HeatLoss;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(BaseCityMap::parse(input)?)
+    }
+
+    fn part_one(base: &Self::Puzzle) -> Result<Self::Answer> {
+        let goal = base.goal();
+
+        RegularCityMap(base)
+            .dial_distance_with(
+                &CruciblePathStep::seeds(),
+                |node| node.position == goal,
+                base.heuristic(),
+            )
+            .ok_or_else(|| anyhow!("the crucible cannot reach the factory"))
+    }
+
+    fn part_two(base: &Self::Puzzle) -> Result<Self::Answer> {
+        let goal = base.goal();
+
+        UltracrucibleCityMap(base)
+            .dial_distance_with(
+                &CruciblePathStep::seeds(),
+                |node| node.position == goal && node.consecutive >= 4,
+                base.heuristic(),
+            )
+            .ok_or_else(|| anyhow!("the ultra crucible cannot reach the factory"))
+    }
+}
 
 impl BaseCityMap {
-    pub fn parse(input: &str) -> Self {
-        let data = input
-            .lines()
-            .flat_map(|line| line.chars())
-            .map(|c| c.to_digit(10).unwrap() as HeatLoss)
-            .collect();
-        let width = input.lines().next().unwrap().len();
-        Self(Matrix { data, width })
+    pub fn parse(input: &str) -> ParseResult<Self> {
+        let grid = Grid::parse(input, |c| c.to_digit(10).map(HeatLoss::from))?;
+        if grid.get_width() == 0 {
+            return Err(ParseError::end_of_input(
+                input,
+                ParseErrorKind::Expected("at least one city block"),
+            ));
+        }
+        Ok(Self(grid))
+    }
+
+    pub fn goal(&self) -> UPoint2D {
+        UPoint2D::from(self.0.get_height() - 1, self.0.get_width() - 1)
+    }
+
+    // The heat lost on the way to the goal by a crucible that could turn and
+    // go straight as it pleases. Neither crucible can do better, so this is
+    // admissible for both, and it never drops by more than the block entered.
+    pub fn heuristic(&self) -> impl Fn(&CruciblePathStep) -> HeatLoss {
+        let to_goal = FreeCityMap(self).distances_from(&[self.goal()]);
+        let to_goal = Grid::new(
+            self.0
+                .positions()
+                .map(|position| to_goal[&position])
+                .collect(),
+            self.0.get_width(),
+        );
+
+        move |step| *to_goal.at(step.position)
+    }
+
+    // Draws the route over the map like the puzzle statement does, with an
+    // arrow on every block the crucible enters.
+    pub fn render_route(&self, route: &Path<CruciblePathStep, HeatLoss>) -> String {
+        let mut canvas = self.0.map(|&heat_loss| {
+            char::from_digit(heat_loss as u32, 10).expect("heat loss is a single digit")
+        });
+        for step in route.nodes.iter().skip(1) {
+            *canvas.at_mut(step.position) = match step.direction {
+                Direction::Up => '^',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+                Direction::Right => '>',
+            };
+        }
+        canvas.to_string()
+    }
+}
+
+impl graph::Graph<UPoint2D, HeatLoss> for FreeCityMap<'_> {
+    fn neighbours(&self, node: &UPoint2D) -> Vec<(HeatLoss, UPoint2D)> {
+        let heat_loss = *self.0 .0.at(*node);
+        self.0
+             .0
+            .neighbours(*node)
+            .into_iter()
+            .map(|neighbour| (heat_loss, neighbour))
+            .collect()
     }
 }
 
@@ -45,6 +150,61 @@ where
     }
 }
 
+// Steps are numbered by position first, then direction, then consecutive blocks.
+impl<CityMapT> graph::IndexedGraph<CruciblePathStep, HeatLoss> for CityMapT
+where
+    CityMapT: CityMap,
+{
+    fn node_count(&self) -> usize {
+        self.get_height() * self.get_width() * 4 * (MAX_CONSECUTIVE + 1)
+    }
+
+    fn index(&self, node: &CruciblePathStep) -> usize {
+        let position = (node.position.row - 1) * self.get_width() + node.position.column - 1;
+        (position * 4 + node.direction as usize) * (MAX_CONSECUTIVE + 1) + node.consecutive as usize
+    }
+}
+
+impl CityMap for RegularCityMap<'_> {
+    fn get_height(&self) -> usize {
+        self.0 .0.get_height()
+    }
+
+    fn get_width(&self) -> usize {
+        self.0 .0.get_width()
+    }
+
+    fn is_valid_step(&self, _: &CruciblePathStep, candidate: &CruciblePathStep) -> bool {
+        candidate.consecutive <= 3
+    }
+
+    fn heat_loss_at(&self, position: UPoint2D) -> HeatLoss {
+        *self.0 .0.at(position)
+    }
+}
+
+impl CityMap for UltracrucibleCityMap<'_> {
+    fn get_height(&self) -> usize {
+        self.0 .0.get_height()
+    }
+
+    fn get_width(&self) -> usize {
+        self.0 .0.get_width()
+    }
+
+    fn is_valid_step(&self, current: &CruciblePathStep, candidate: &CruciblePathStep) -> bool {
+        if current.direction == candidate.direction {
+            candidate.consecutive <= 10
+        } else {
+            current.consecutive >= 4
+        }
+    }
+
+    fn heat_loss_at(&self, position: UPoint2D) -> HeatLoss {
+        *self.0 .0.at(position)
+    }
+}
+
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub struct CruciblePathStep {
     pub position: UPoint2D,
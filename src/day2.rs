@@ -1,3 +1,9 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
 use std::cmp;
 use std::collections::HashMap;
 
@@ -9,12 +15,15 @@ pub enum Color {
 }
 
 impl Color {
-    fn parse(raw_color: &str) -> Self {
+    fn parse(line: Line, raw_color: &str) -> ParseResult<Self> {
         match raw_color {
-            "red" => Color::Red,
-            "green" => Color::Green,
-            "blue" => Color::Blue,
-            _ => panic!("error parsing color"),
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(line.error_at(
+                raw_color,
+                ParseErrorKind::UnexpectedToken(raw_color.to_string()),
+            )),
         }
     }
 }
@@ -25,11 +34,56 @@ pub struct Game {
     pub samples: Vec<Vec<(u32, Color)>>,
 }
 
-pub fn maxima(samples: Vec<Vec<(u32, Color)>>) -> (u32, u32, u32) {
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Puzzle = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(games: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(possible_games_checksum(games))
+    }
+
+    fn part_two(games: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(total_power(games))
+    }
+}
+
+fn possible_games_checksum(games: &[Game]) -> u32 {
+    let mut accumulator = 0;
+    let h0 = (12, 13, 14);
+
+    for game in games {
+        let estimator = maxima(&game.samples);
+        if estimator.0 <= h0.0 && estimator.1 <= h0.1 && estimator.2 <= h0.2 {
+            accumulator += game.id;
+        }
+    }
+
+    accumulator
+}
+
+fn total_power(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| maxima(&game.samples))
+        .map(power)
+        .sum()
+}
+
+fn power((red, green, blue): (u32, u32, u32)) -> u32 {
+    red * green * blue
+}
+
+pub fn maxima(samples: &[Vec<(u32, Color)>]) -> (u32, u32, u32) {
     let mut accumulator = HashMap::new();
 
     for sample in samples {
-        for (count, color) in sample {
+        for &(count, color) in sample {
             let current = *accumulator.get(&color).unwrap_or(&0);
             accumulator.insert(color, cmp::max(current, count));
         }
@@ -42,27 +96,30 @@ pub fn maxima(samples: Vec<Vec<(u32, Color)>>) -> (u32, u32, u32) {
     )
 }
 
-pub fn parse_input(raw_input: &str) -> Vec<Game> {
-    raw_input.lines().map(parse_line).collect()
+pub fn parse_input(raw_input: &str) -> ParseResult<Vec<Game>> {
+    parse::lines(raw_input).map(parse_line).collect()
 }
 
-fn parse_line(line: &str) -> Game {
-    let (prefix, rest) = line.split_once(": ").unwrap();
-    let id: u32 = prefix.split_once(' ').unwrap().1.parse().unwrap();
-    let samples = rest.split("; ").map(parse_sample).collect();
+fn parse_line(line: Line) -> ParseResult<Game> {
+    let (prefix, rest) = line.split_once(line.text, ": ")?;
+    let id = line.number(line.split_once(prefix, " ")?.1)?;
+    let samples = rest
+        .split("; ")
+        .map(|raw_sample| parse_sample(line, raw_sample))
+        .collect::<ParseResult<_>>()?;
 
-    Game { id, samples }
+    Ok(Game { id, samples })
 }
 
-fn parse_sample(raw_sample: &str) -> Vec<(u32, Color)> {
+fn parse_sample(line: Line, raw_sample: &str) -> ParseResult<Vec<(u32, Color)>> {
     raw_sample
         .split(", ")
         .map(|entry| {
-            let (raw_count, raw_color) = entry.split_once(' ').unwrap();
-            let count: u32 = raw_count.parse().unwrap();
-            let color: Color = Color::parse(raw_color);
+            let (raw_count, raw_color) = line.split_once(entry, " ")?;
+            let count: u32 = line.number(raw_count)?;
+            let color: Color = Color::parse(line, raw_color)?;
 
-            (count, color)
+            Ok((count, color))
         })
         .collect()
 }
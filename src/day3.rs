@@ -1,130 +1,144 @@
-use std::cmp;
+use crate::common::Grid;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use crate::spatial::Rectangle;
+use crate::spatial::UPoint2D;
+use anyhow::Result;
 use std::collections::HashMap;
-use std::iter::Peekable;
 
 #[derive(Debug)]
 pub struct Schematic {
     pub numbers: Vec<Number>,
-    pub symbols: HashMap<Position, Symbol>,
+    pub symbols: HashMap<UPoint2D, Symbol>,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct Number {
     pub value: u32,
-    pub position: Position, // leftmost
+    pub position: UPoint2D, // leftmost
     pub length: usize,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct Position {
-    row: usize,
-    column: usize,
+type Symbol = char;
+type Gear = (u32, u32);
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Puzzle = Schematic;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(schematic: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(sum_part_numbers(schematic))
+    }
+
+    fn part_two(schematic: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(find_gears(schematic).iter().map(calculate_gear_ratio).sum())
+    }
 }
 
-type Symbol = char;
+fn sum_part_numbers(schematic: &Schematic) -> u32 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|&number| is_part_number(schematic, *number))
+        .map(|&number| number.value)
+        .sum()
+}
 
-pub fn perimeter(number: Number) -> Vec<Position> {
-    let r = number.position.row;
-    let c = number.position.column;
-    let top_left = Position {
-        row: r.saturating_sub(1),
-        column: c.saturating_sub(1),
-    };
-    let bottom_left = Position {
-        row: r + 1,
-        column: c.saturating_sub(1),
-    };
-    let top_right = Position {
-        row: r.saturating_sub(1),
-        column: c + number.length,
-    };
-    let bottom_right = Position {
-        row: r + 1,
-        column: c + number.length,
-    };
-    let mut result = vec![];
-    //result.append(&mut segment(top_left, bottom_left));
-    result.push(Position {
-        row: r,
-        column: c.saturating_sub(1),
-    });
-    result.append(&mut segment(top_left, top_right));
-    result.append(&mut segment(bottom_left, bottom_right));
-    result.push(Position {
-        row: r,
-        column: c + number.length,
-    });
-    //result.append(&mut segment(top_right, bottom_right));
-    result
+fn is_part_number(schematic: &Schematic, number: Number) -> bool {
+    perimeter(number).any(|position| schematic.symbols.contains_key(&position))
+}
+
+fn calculate_gear_ratio((x, y): &Gear) -> u32 {
+    x * y
 }
 
-fn segment(a: Position, b: Position) -> Vec<Position> {
-    if a.row == b.row {
-        let min = cmp::min(a.column, b.column);
-        let max = cmp::max(a.column, b.column);
-        (min..=max)
-            .map(|column| Position { row: a.row, column })
-            .collect()
-    } else if a.column == b.column {
-        let min = cmp::min(a.row, b.row);
-        let max = cmp::max(a.row, b.row);
-        (min..=max)
-            .map(|row| Position {
-                row,
-                column: a.column,
-            })
-            .collect()
-    } else {
-        panic!("unable to generate segment")
+fn find_gears(schematic: &Schematic) -> Vec<Gear> {
+    let mut star_numbers: HashMap<UPoint2D, Vec<u32>> = HashMap::new();
+
+    for &number in schematic.numbers.iter() {
+        for star_position in find_surrounding_star(schematic, number) {
+            star_numbers
+                .entry(star_position)
+                .or_default()
+                .push(number.value);
+        }
     }
+
+    star_numbers
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| (numbers[0], numbers[1]))
+        .collect()
+}
+
+fn find_surrounding_star(schematic: &Schematic, number: Number) -> Vec<UPoint2D> {
+    perimeter(number)
+        .filter(|position| {
+            schematic
+                .symbols
+                .get(position)
+                .is_some_and(|&symbol| symbol == '*')
+        })
+        .collect()
 }
 
-pub fn parse_input(raw_input: &str) -> Schematic {
+pub fn perimeter(number: Number) -> impl Iterator<Item = UPoint2D> {
+    let last_digit = UPoint2D {
+        column: number.position.column + number.length - 1,
+        ..number.position
+    };
+    Rectangle::new(number.position, last_digit)
+        .grow(1)
+        .perimeter()
+}
+
+pub fn parse_input(raw_input: &str) -> ParseResult<Schematic> {
+    let lines: Vec<_> = parse::lines(raw_input).collect();
+    // Anything that is neither a digit nor a '.' is a symbol.
+    let grid = Grid::parse_lines(&lines, Some)?;
     let mut numbers = vec![];
     let mut symbols = HashMap::new();
 
-    for (row, line) in raw_input.lines().enumerate() {
-        let mut chars = line.chars().enumerate().peekable();
-        loop {
-            match chars.peek() {
-                None => break,
-                Some((_, '.')) => {
-                    chars.next();
-                }
-                Some((_, ch)) if ch.is_digit(10) => numbers.push(consume_number(&mut chars, row)),
-                Some((column, ch)) => {
-                    symbols.insert(
-                        Position {
-                            row,
-                            column: *column,
-                        },
-                        *ch,
-                    );
-                    chars.next();
-                }
+    for (row, (line, cells)) in lines.iter().zip(grid.rows()).enumerate() {
+        let mut column = 0;
+        while let Some(&cell) = cells.get(column) {
+            if cell.is_ascii_digit() {
+                let number = parse_number(*line, row, column, cells)?;
+                column += number.length;
+                numbers.push(number);
+                continue;
+            }
+            if cell != '.' {
+                symbols.insert(UPoint2D::from(row, column), cell);
             }
+            column += 1;
         }
     }
 
-    Schematic { numbers, symbols }
+    Ok(Schematic { numbers, symbols })
 }
 
-fn consume_number(chars: &mut Peekable<impl Iterator<Item = (usize, char)>>, row: usize) -> Number {
-    let mut indexed_digits: Vec<(usize, char)> = vec![];
-    while chars.peek().is_some_and(|(_, ch)| ch.is_digit(10)) {
-        indexed_digits.push(chars.next().unwrap());
-    }
-    let length = indexed_digits.len();
-    let column = indexed_digits[0].0;
-    let value = indexed_digits
+// Reads the number whose leftmost digit is at the column of the row.
+fn parse_number(line: Line, row: usize, column: usize, cells: &[char]) -> ParseResult<Number> {
+    let length = cells[column..]
         .iter()
-        .map(|digit| digit.1)
-        .collect::<String>()
-        .parse()
-        .unwrap();
-    Number {
+        .take_while(|cell| cell.is_ascii_digit())
+        .count();
+    // Columns count characters, while the text is sliced by bytes.
+    let (offset, _) = line.text.char_indices().nth(column).unwrap();
+    let value = line.number(&line.text[offset..offset + length])?;
+
+    Ok(Number {
         value,
-        position: Position { row, column },
+        position: UPoint2D::from(row, column),
         length,
-    }
+    })
 }
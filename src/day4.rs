@@ -1,5 +1,9 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
 use std::collections;
-use std::str;
 
 type Number = u8;
 pub struct Scratchcard {
@@ -7,12 +11,45 @@ pub struct Scratchcard {
     present: collections::HashSet<Number>,
 }
 
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Puzzle = Vec<Scratchcard>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(cards: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(cards.iter().map(Scratchcard::score).sum())
+    }
+
+    fn part_two(cards: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(count_copies(cards))
+    }
+}
+
+fn count_copies(cards: &[Scratchcard]) -> u64 {
+    let mut counts = vec![1; cards.len()];
+
+    for idx in 0..cards.len() {
+        let current_count = counts[idx];
+        let matches_count = cards[idx].count_matches();
+
+        for delta_idx in 0..matches_count {
+            if let Some(count) = counts.get_mut(idx + delta_idx + 1) {
+                *count += current_count;
+            }
+        }
+    }
+
+    counts.iter().sum()
+}
+
 impl Scratchcard {
     pub fn count_matches(&self) -> usize {
-        self.winning
-            .intersection(&self.present)
-            .collect::<Vec<_>>()
-            .len()
+        self.winning.intersection(&self.present).count()
     }
 
     pub fn score(&self) -> u64 {
@@ -26,20 +63,17 @@ impl Scratchcard {
     }
 }
 
-pub struct ParseScratchcardErr;
-impl str::FromStr for Scratchcard {
-    type Err = ParseScratchcardErr;
-    fn from_str(input: &str) -> Result<Scratchcard, Self::Err> {
-        let (raw_winning, raw_present) = input.split_once(':').unwrap().1.split_once('|').unwrap();
+impl Scratchcard {
+    fn parse(line: Line) -> ParseResult<Scratchcard> {
+        let (_, numbers) = line.split_once(line.text, ":")?;
+        let (raw_winning, raw_present) = line.split_once(numbers, "|")?;
         Ok(Scratchcard {
-            winning: raw_winning
-                .split_ascii_whitespace()
-                .flat_map(str::parse)
-                .collect(),
-            present: raw_present
-                .split_ascii_whitespace()
-                .flat_map(str::parse)
-                .collect(),
+            winning: line.numbers::<Number>(raw_winning)?.into_iter().collect(),
+            present: line.numbers::<Number>(raw_present)?.into_iter().collect(),
         })
     }
 }
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<Scratchcard>> {
+    parse::lines(input).map(Scratchcard::parse).collect()
+}
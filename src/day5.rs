@@ -1,4 +1,11 @@
-use std::str;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::anyhow;
+use anyhow::Result;
 
 #[derive(Debug)]
 pub struct Map {
@@ -14,6 +21,194 @@ pub struct Piece {
     pub size: Identifier,
 }
 
+// Equivalent to Map, but explicitly claims the saturation property.
+// That means there are no gaps in the pieces (except at the end,
+// because infinity does not fit in a u64) and they are sorted.
+struct SaturatedMap {
+    pieces: Vec<Piece>,
+}
+
+struct Range {
+    from: Identifier,
+    size: Identifier,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Puzzle = (Vec<Identifier>, Vec<Map>);
+    type Answer = Identifier;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one((seeds, maps): &Self::Puzzle) -> Result<Self::Answer> {
+        seeds
+            .iter()
+            .map(|&seed| apply_all(seed, maps))
+            .min()
+            .ok_or_else(|| anyhow!("there are no seeds to plant"))
+    }
+
+    // The second part reads the seeds as (start, size) pairs describing ranges.
+    fn part_two((seeds, maps): &Self::Puzzle) -> Result<Self::Answer> {
+        if seeds.len() % 2 != 0 {
+            return Err(anyhow!("seed ranges must come in (start, size) pairs"));
+        }
+        let ranges = to_ranges(seeds);
+        let final_map = maps
+            .iter()
+            .map(saturate)
+            .reduce(SaturatedMap::and_then)
+            .ok_or_else(|| anyhow!("there are no maps to apply"))?;
+
+        ranges
+            .iter()
+            .flat_map(|range| explode_range(range, &final_map))
+            .map(|seed| final_map.apply(seed))
+            .min()
+            .ok_or_else(|| anyhow!("there are no seeds to plant"))
+    }
+}
+
+fn apply_all(seed: Identifier, maps: &[Map]) -> Identifier {
+    maps.iter().fold(seed, |seed, map| map.apply(seed))
+}
+
+fn explode_range(range: &Range, map: &SaturatedMap) -> Vec<Identifier> {
+    let mut current = range.from;
+    let mut breakpoints = vec![];
+
+    while current < range.from + range.size {
+        breakpoints.push(current);
+        match map.locate_piece(current) {
+            Some((_, piece)) => current = piece.from + piece.size,
+            None => break,
+        }
+    }
+
+    breakpoints
+}
+
+fn saturate(map: &Map) -> SaturatedMap {
+    let mut sorted = map.pieces.clone();
+    sorted.sort();
+    if sorted[0].from > 0 {
+        sorted.insert(
+            0,
+            Piece {
+                from: 0,
+                to: 0,
+                size: sorted[0].from,
+            },
+        )
+    }
+
+    let mut new_pieces = Vec::with_capacity(sorted.len());
+    for idx in 0..sorted.len() {
+        new_pieces.push(sorted[idx].clone());
+        let is_gap =
+            idx + 1 < sorted.len() && sorted[idx].from + sorted[idx].size < sorted[idx + 1].from;
+        if is_gap {
+            let gap_start = sorted[idx].from + sorted[idx].size;
+            new_pieces.push(Piece {
+                from: gap_start,
+                to: gap_start,
+                size: sorted[idx + 1].from - gap_start,
+            });
+        }
+    }
+
+    SaturatedMap { pieces: new_pieces }
+}
+
+impl SaturatedMap {
+    fn apply(&self, seed: Identifier) -> Identifier {
+        self.locate_piece(seed)
+            .map(|(_, piece)| piece.to + (seed - piece.from))
+            .unwrap_or(seed)
+    }
+
+    // TODO: could use binary search
+    fn locate_piece(&self, target: Identifier) -> Option<(usize, &Piece)> {
+        self.pieces
+            .iter()
+            .enumerate()
+            .find(|(_, piece)| piece.from <= target && target < piece.from + piece.size)
+    }
+
+    fn and_then(self, other: SaturatedMap) -> SaturatedMap {
+        let mut pieces = vec![];
+
+        let mut self_idx = 0;
+        let mut current = 0;
+        let end = self.pieces[self.pieces.len() - 1].from + self.pieces[self.pieces.len() - 1].size;
+
+        while current < end {
+            while self_idx < self.pieces.len()
+                && current >= self.pieces[self_idx].from + self.pieces[self_idx].size
+            {
+                self_idx += 1;
+            }
+            let destination = self.pieces[self_idx].to + (current - self.pieces[self_idx].from);
+            let other_piece = other.locate_piece(destination);
+            let new_piece;
+            (current, new_piece) = match other_piece {
+                Some((_, other_piece)) => {
+                    compose_pieces(current, &self.pieces[self_idx], other_piece)
+                }
+                None => (
+                    self.pieces[self_idx].from + self.pieces[self_idx].size,
+                    Piece {
+                        from: current,
+                        to: self.pieces[self_idx].to + (current - self.pieces[self_idx].from),
+                        size: self.pieces[self_idx].size - (current - self.pieces[self_idx].from),
+                    },
+                ),
+            };
+            pieces.push(new_piece);
+        }
+
+        let other_remaining = other.locate_piece(end);
+        if let Some((other_idx, other_piece)) = other_remaining {
+            pieces.push(Piece {
+                from: end,
+                to: other_piece.to + end - other_piece.from,
+                size: other_piece.size - (end - other_piece.from),
+            });
+            pieces.extend_from_slice(&other.pieces[other_idx + 1..]);
+        }
+
+        // TODO: contiguous pieces might be redundant
+        // e.g. 1 0 5, 6 5 10 -> 1 0 15
+        SaturatedMap { pieces }
+    }
+}
+
+fn compose_pieces(seed: Identifier, first: &Piece, second: &Piece) -> (Identifier, Piece) {
+    let destination = first.to + (seed - first.from);
+    if first.to + first.size < second.from + second.size {
+        (
+            first.from + first.size,
+            Piece {
+                from: seed,
+                to: second.to + (destination - second.from),
+                size: first.size - (seed - first.from),
+            },
+        )
+    } else {
+        (
+            seed + second.size - (destination - second.from),
+            Piece {
+                from: seed,
+                to: second.to + (destination - second.from),
+                size: second.size - (destination - second.from),
+            },
+        )
+    }
+}
+
 impl Map {
     pub fn apply(&self, seed: Identifier) -> Identifier {
         self.pieces
@@ -24,24 +219,53 @@ impl Map {
     }
 }
 
-pub struct ParseMapErr;
-impl str::FromStr for Map {
-    type Err = ParseMapErr;
-    fn from_str(input: &str) -> Result<Map, Self::Err> {
+impl Map {
+    // The first line is just the header naming the categories being mapped.
+    fn parse(block: &[Line]) -> ParseResult<Map> {
         Ok(Map {
-            pieces: input.lines().skip(1).flat_map(str::parse).collect(),
+            pieces: block
+                .iter()
+                .skip(1)
+                .map(|&line| Piece::parse(line))
+                .collect::<ParseResult<_>>()?,
         })
     }
 }
 
-pub struct ParsePieceErr;
-impl str::FromStr for Piece {
-    type Err = ParsePieceErr;
-    fn from_str(input: &str) -> Result<Piece, Self::Err> {
-        let nums: Vec<Identifier> = input.split(' ').flat_map(str::parse).collect();
-        match nums[..] {
+impl Piece {
+    fn parse(line: Line) -> ParseResult<Piece> {
+        match line.numbers::<Identifier>(line.text)?[..] {
             [to, from, size] => Ok(Piece { from, to, size }),
-            _ => Err(ParsePieceErr),
+            _ => Err(line.error_at(
+                line.text,
+                ParseErrorKind::Expected("three numbers: destination, source and size"),
+            )),
         }
     }
 }
+
+pub fn parse_input(input: &str) -> ParseResult<(Vec<Identifier>, Vec<Map>)> {
+    let blocks = parse::blocks(input);
+    let (seeds_block, rest) = blocks.split_first().ok_or_else(|| {
+        ParseError::end_of_input(input, ParseErrorKind::Expected("a list of seeds"))
+    })?;
+    let seeds_line = seeds_block[0];
+    let (_, raw_seeds) = seeds_line.split_once(seeds_line.text, ":")?;
+    let seeds = seeds_line.numbers(raw_seeds)?;
+    let maps = rest
+        .iter()
+        .map(|block| Map::parse(block))
+        .collect::<ParseResult<_>>()?;
+
+    Ok((seeds, maps))
+}
+
+fn to_ranges(raw_ranges: &[Identifier]) -> Vec<Range> {
+    raw_ranges
+        .chunks(2)
+        .map(|chunk| Range {
+            from: chunk[0],
+            size: chunk[1],
+        })
+        .collect()
+}
@@ -1,54 +1,111 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
+use std::iter::zip;
+
 pub type Time = u64;
 pub type Distance = u64;
 
-// Represents ax² + bx + c
-struct SecondOrderPolynomial(i64, i64, i64);
+pub struct Races {
+    pub times: Vec<Time>,
+    pub distances: Vec<Distance>,
+    // Each line read as a single number, ignoring the spaces between digits.
+    pub kerned_time: Time,
+    pub kerned_distance: Distance,
+}
 
-pub fn beat_record(total_time: Time, target_distance: Distance) -> u64 {
-    // t * (T - t) >= D
-    // -t² + Tt - D >= 0
-    // t² - Tt + D >= 0
-    let polynomial = SecondOrderPolynomial(1, -(total_time as i64), target_distance as i64);
-
-    match solve_equation(polynomial) {
-        Some((a, b)) => {
-            // The inequality is strict so the edges are exclusive.
-            // If they are already integers we need to pad them.
-            let left: i64 = if is_integer(a) {
-                a as i64 + 1
-            } else {
-                a.ceil() as i64
-            };
-            let right: i64 = if is_integer(b) {
-                b as i64 - 1
-            } else {
-                b.floor() as i64
-            };
-            (right - left) as u64 + 1
-        }
-        None => 0,
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Puzzle = Races;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(races: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(zip(&races.times, &races.distances)
+            .map(|(&total_time, &target_distance)| beat_record(total_time, target_distance))
+            .product())
+    }
+
+    fn part_two(races: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(beat_record(races.kerned_time, races.kerned_distance))
     }
 }
 
-pub fn is_integer(x: f64) -> bool {
-    x == (x as u64) as f64
+// Holding the button for t out of T milliseconds covers t * (T - t) millimetres,
+// which beats the record D for every t strictly between the roots of
+// t² - Tt + D, i.e. (T ± √(T² - 4D)) / 2. Everything is computed on integers,
+// wide enough for T² not to overflow, since floats lose the precision needed
+// to tell where the roots fall once T reaches about 10^8.
+pub fn beat_record(total_time: Time, target_distance: Distance) -> u64 {
+    let (total_time, target_distance) = (u128::from(total_time), u128::from(target_distance));
+    let beats = |hold: u128| hold * (total_time - hold) > target_distance;
+    let Some(discriminant) = (total_time * total_time).checked_sub(4 * target_distance) else {
+        return 0;
+    };
+
+    // The integer square root puts the estimate at most one off the smallest
+    // winning hold time, unless there is none at all.
+    let mut shortest = (total_time - discriminant.isqrt()) / 2;
+    while shortest > 0 && beats(shortest - 1) {
+        shortest -= 1;
+    }
+    while shortest <= total_time / 2 && !beats(shortest) {
+        shortest += 1;
+    }
+
+    // The distances are symmetric around T / 2, so the longest winning hold
+    // time is T - shortest.
+    (total_time + 1).saturating_sub(2 * shortest) as u64
 }
 
-// Returns the (real) roots of a second-order equation.
-// If the roots are complex, it returns None.
-// Double roots are duplicated, it is up to the caller to handle them.
-fn solve_equation(polynomial: SecondOrderPolynomial) -> Option<(f64, f64)> {
-    let (a, b, c) = (polynomial.0, polynomial.1, polynomial.2);
-    let minus_b: f64 = -b as f64;
-    let discriminant: f64 = (b * b - 4 * a * c) as f64;
-    let denominator: f64 = (2 * a) as f64;
-
-    if discriminant <= 0.0 {
-        None
-    } else {
-        Some((
-            (minus_b - discriminant.sqrt()) / denominator,
-            (minus_b + discriminant.sqrt()) / denominator,
-        ))
+pub fn parse_input(input: &str) -> ParseResult<Races> {
+    let mut lines = parse::lines(input);
+    let missing_line =
+        |expected| ParseError::end_of_input(input, ParseErrorKind::Expected(expected));
+    let time_line = lines
+        .next()
+        .ok_or_else(|| missing_line("a line of times"))?;
+    let distance_line = lines
+        .next()
+        .ok_or_else(|| missing_line("a line of distances"))?;
+    let (times, kerned_time) = parse_line(time_line)?;
+    let (distances, kerned_distance) = parse_line(distance_line)?;
+
+    if times.len() != distances.len() {
+        return Err(distance_line.error_at(
+            distance_line.text,
+            ParseErrorKind::Expected("as many distances as times"),
+        ));
     }
+
+    Ok(Races {
+        times,
+        distances,
+        kerned_time,
+        kerned_distance,
+    })
+}
+
+// The numbers on the line, and all of their digits read as one number. The
+// digits are joined as written, so that leading zeros are kept.
+fn parse_line(line: Line) -> ParseResult<(Vec<u64>, u64)> {
+    let (_, raw_values) = line.split_once(line.text, ":")?;
+    let values = line.numbers(raw_values)?;
+    let digits: String = raw_values.split_ascii_whitespace().collect();
+    let kerned = digits.parse().map_err(|_| {
+        line.error_at(
+            raw_values.trim_start(),
+            ParseErrorKind::InvalidNumber(digits.clone()),
+        )
+    })?;
+
+    Ok((values, kerned))
 }
@@ -0,0 +1,183 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
+use std::cmp;
+use std::collections::HashMap;
+use std::iter::zip;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandType {
+    High,
+    Pair,
+    TwoPair,
+    Three,
+    Full,
+    Four,
+    Five,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Hand {
+    cards: [Card; 5],
+}
+
+pub type Bid = u32;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Puzzle = Vec<(Hand, Bid)>;
+    type Answer = Bid;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(hands: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(total_winnings(hands.clone()))
+    }
+
+    fn part_two(hands: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(total_winnings(
+            hands
+                .iter()
+                .map(|&(hand, bid)| (hand.with_jokers(), bid))
+                .collect(),
+        ))
+    }
+}
+
+fn total_winnings(mut hands: Vec<(Hand, Bid)>) -> Bid {
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, bid))| ((idx + 1) as Bid) * bid)
+        .sum()
+}
+
+impl Hand {
+    // In the second part every Jack becomes a Joker, which is weaker than
+    // any other card but can stand in for whichever makes the best hand.
+    pub fn with_jokers(self) -> Self {
+        Hand {
+            cards: self.cards.map(|card| match card {
+                Card::Jack => Card::Joker,
+                other => other,
+            }),
+        }
+    }
+
+    fn get_type(&self) -> HandType {
+        let mut counter: HashMap<Card, usize> = HashMap::new();
+        for card in self.cards {
+            *counter.entry(card).or_default() += 1;
+        }
+        let jokers_count = counter.remove(&Card::Joker).unwrap_or(0);
+
+        // Jokers always do best joining the largest group of cards.
+        let mut group_sizes: Vec<_> = counter.into_values().collect();
+        group_sizes.sort_unstable_by(|a, b| b.cmp(a));
+        match group_sizes.first_mut() {
+            Some(largest) => *largest += jokers_count,
+            None => group_sizes.push(jokers_count),
+        }
+
+        match (group_sizes[0], group_sizes.get(1).copied().unwrap_or(0)) {
+            (5, _) => HandType::Five,
+            (4, _) => HandType::Four,
+            (3, 2) => HandType::Full,
+            (3, _) => HandType::Three,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::Pair,
+            _ => HandType::High,
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match self.get_type().cmp(&other.get_type()) {
+            cmp::Ordering::Equal => zip(self.cards, other.cards)
+                .find(|(self_card, other_card)| self_card != other_card)
+                .map(|(self_card, other_card)| self_card.cmp(&other_card))
+                .unwrap_or(cmp::Ordering::Equal),
+            other => other,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    fn parse(line: Line, raw_hand: &str) -> ParseResult<Hand> {
+        let cards = line
+            .char_indices(raw_hand)
+            .map(|(offset, input)| {
+                Card::parse(input).ok_or_else(|| line.unexpected_character(offset, input))
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        match cards[..] {
+            [first, second, third, fourth, fifth] => Ok(Hand {
+                cards: [first, second, third, fourth, fifth],
+            }),
+            _ => Err(line.error_at(raw_hand, ParseErrorKind::Expected("exactly five cards"))),
+        }
+    }
+}
+
+impl Card {
+    fn parse(input: char) -> Option<Card> {
+        match input {
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<Vec<(Hand, Bid)>> {
+    parse::lines(input).map(parse_line).collect()
+}
+
+fn parse_line(line: Line) -> ParseResult<(Hand, Bid)> {
+    let (raw_hand, raw_bid) = line.split_once(line.text, " ")?;
+    Ok((Hand::parse(line, raw_hand)?, line.number(raw_bid)?))
+}
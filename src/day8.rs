@@ -1,8 +1,20 @@
 use crate::common::Cycle;
 use crate::common::CycleDetection; // importing to have access to the Iterator instance
+use crate::graph::AdjacencyList;
+use crate::graph::EdgeMode;
+use crate::number_theory::Congruence;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
 use anyhow::anyhow;
 use anyhow::Result;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Direction {
@@ -10,11 +22,31 @@ pub enum Direction {
     Right,
 }
 
-pub type NodeID = String;
+// Nodes are numbered in the order they are listed in the input.
+pub type NodeID = usize;
 
-#[derive(Debug, Eq, PartialEq)]
+// Every node has exactly two edges, labelled with the direction that follows them.
+#[derive(Debug)]
 pub struct Network {
-    pub edges: HashMap<NodeID, (NodeID, NodeID)>,
+    pub graph: AdjacencyList<NodeID, Direction>,
+    names: Vec<String>,
+    ids: HashMap<String, NodeID>,
+}
+
+// A node along with the DirectionStream::index of the next direction to take,
+// which together determine the rest of the walk.
+pub type StepKey = (NodeID, usize);
+
+// The times, counted in steps, at which a walk is on a terminal node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TerminalHits {
+    // In increasing order, all of them no later than cycle_start.
+    pub before_cycle: Vec<usize>,
+    // The walk loops from the step after cycle_start onwards, every cycle_length steps.
+    pub cycle_start: usize,
+    pub cycle_length: usize,
+    // The offset into the loop for each terminal step inside it.
+    pub in_cycle: HashMap<StepKey, usize>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -23,15 +55,106 @@ pub struct DirectionStream<'a> {
     pub index: usize,
 }
 
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Puzzle = (Vec<Direction>, Network);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one((directions, network): &Self::Puzzle) -> Result<Self::Answer> {
+        let from = network
+            .id("AAA")
+            .ok_or_else(|| anyhow!("the network has no node AAA to start from"))?;
+        let to = network
+            .id("ZZZ")
+            .ok_or_else(|| anyhow!("the network has no node ZZZ to reach"))?;
+        Ok(distance(from, to, directions, network))
+    }
+
+    fn part_two((directions, network): &Self::Puzzle) -> Result<Self::Answer> {
+        simultaneous_distance(directions, network)
+    }
+}
+
+fn simultaneous_distance(directions: &[Direction], network: &Network) -> Result<usize> {
+    // Every ghost ends up walking a loop, so past that point it is on a terminal
+    // node at times n ≡ T (mod lambda), one congruence per terminal T in the loop.
+    // Each choice of terminals per ghost makes up its own system of congruences.
+    // Their moduli usually share factors (269 in my input), so CRT only applies
+    // after merging them, which may turn out to be impossible for some choices.
+    // Merging ghost by ghost drops those choices early, and choices that merge
+    // to the same congruence are only carried on once.
+    //
+    // Before the last ghost enters its loop, the only candidates are the times
+    // at which that ghost happens to be on a terminal node.
+    let ghosts: Vec<_> = network
+        .nodes()
+        .filter(|&node_id| is_initial(network.name(node_id)))
+        .map(|node_id| network.terminal_hits(node_id, directions, is_terminal))
+        .collect();
+    let last_to_loop = ghosts
+        .iter()
+        .max_by_key(|hits| hits.cycle_start)
+        .ok_or_else(|| anyhow!("there are no initial nodes"))?;
+
+    if let Some(&distance) = last_to_loop
+        .before_cycle
+        .iter()
+        .find(|&&time| ghosts.iter().all(|hits| hits.hits_at(time)))
+    {
+        return Ok(distance);
+    }
+
+    let mut merged: HashSet<Congruence<i128>> = HashSet::from([Congruence::new(0, 1)]);
+    for hits in &ghosts {
+        let terminals = hits.congruences();
+        merged = merged
+            .iter()
+            .flat_map(|congruence| {
+                terminals
+                    .iter()
+                    .filter_map(|terminal| congruence.merge(terminal))
+            })
+            .collect();
+    }
+    let distance = merged
+        .iter()
+        .map(|solution| solution.first_at_least(last_to_loop.cycle_start as i128 + 1))
+        .min()
+        .ok_or_else(|| anyhow!("the ghosts are never all on terminal nodes at once"))?;
+    Ok(distance.try_into()?)
+}
+
+fn distance(from: NodeID, to: NodeID, directions: &[Direction], network: &Network) -> usize {
+    let step = Step {
+        node_id: from,
+        network,
+        direction_stream: DirectionStream::from_directions(directions),
+    };
+    1 + step
+        .into_iter()
+        .take_while(|current| current.node_id != to)
+        .count()
+}
+
+fn is_initial(name: &str) -> bool {
+    name.ends_with('A')
+}
+
+fn is_terminal(name: &str) -> bool {
+    name.ends_with('Z')
+}
+
 impl Direction {
-    fn parse(input: char) -> Result<Direction> {
+    fn parse(input: char) -> Option<Direction> {
         match input {
-            'L' => Ok(Direction::Left),
-            'R' => Ok(Direction::Right),
-            _ => Err(anyhow!(
-                "could not parse the following direction: {}",
-                input
-            )),
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
         }
     }
 }
@@ -52,20 +175,104 @@ impl<'a> DirectionStream<'a> {
 }
 
 impl Network {
-    pub fn detect_cycle(&self, seed: &NodeID, directions: &[Direction]) -> Cycle {
+    pub fn id(&self, name: &str) -> Option<NodeID> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, node_id: NodeID) -> &str {
+        &self.names[node_id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeID> {
+        0..self.names.len()
+    }
+
+    pub fn follow(&self, node_id: NodeID, direction: Direction) -> NodeID {
+        self.graph
+            .edges_from(&node_id)
+            .iter()
+            .find(|&&(label, _)| label == direction)
+            .map(|&(_, next)| next)
+            .expect("every node has an edge for both directions")
+    }
+
+    pub fn detect_cycle<'a>(
+        &'a self,
+        seed: NodeID,
+        directions: &'a [Direction],
+    ) -> Cycle<Step<'a>> {
         let step = Step {
             node_id: seed,
             network: self,
             direction_stream: DirectionStream::from_directions(directions),
         };
 
-        step.detect_cycle()
+        step.detect_cycle().expect("walking the network never ends")
+    }
+
+    pub fn terminal_hits(
+        &self,
+        seed: NodeID,
+        directions: &[Direction],
+        is_terminal: impl Fn(&str) -> bool,
+    ) -> TerminalHits {
+        let cycle = self.detect_cycle(seed, directions);
+        let is_terminal_step = |step: &Step| is_terminal(self.name(step.node_id));
+        let prefix = Step {
+            node_id: seed,
+            network: self,
+            direction_stream: DirectionStream::from_directions(directions),
+        };
+
+        // The step at index i of the walk is the one reached after i + 1 moves.
+        TerminalHits {
+            before_cycle: prefix
+                .into_iter()
+                .take(cycle.mu)
+                .enumerate()
+                .filter(|(_, step)| is_terminal_step(step))
+                .map(|(index, _)| index + 1)
+                .collect(),
+            cycle_start: cycle.mu,
+            cycle_length: cycle.lambda,
+            in_cycle: cycle
+                .states
+                .iter()
+                .enumerate()
+                .filter(|(_, step)| is_terminal_step(step))
+                .map(|(offset, step)| (step.key(), offset))
+                .collect(),
+        }
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+impl TerminalHits {
+    pub fn hits_at(&self, time: usize) -> bool {
+        if time <= self.cycle_start {
+            self.before_cycle.binary_search(&time).is_ok()
+        } else {
+            let offset = (time - self.cycle_start - 1) % self.cycle_length;
+            self.in_cycle.values().any(|&hit| hit == offset)
+        }
+    }
+
+    // The times inside the loop at which each terminal step comes up.
+    pub fn congruences(&self) -> Vec<Congruence<i128>> {
+        self.in_cycle
+            .values()
+            .map(|&offset| {
+                Congruence::new(
+                    (self.cycle_start + offset + 1) as i128,
+                    self.cycle_length as i128,
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Step<'a> {
-    pub node_id: &'a NodeID,
+    pub node_id: NodeID,
     pub network: &'a Network,
     pub direction_stream: DirectionStream<'a>,
 }
@@ -74,6 +281,12 @@ pub struct StepIter<'a> {
     step: Step<'a>,
 }
 
+impl Step<'_> {
+    pub fn key(&self) -> StepKey {
+        (self.node_id, self.direction_stream.index)
+    }
+}
+
 impl<'a> IntoIterator for Step<'a> {
     type Item = Step<'a>;
     type IntoIter = StepIter<'a>;
@@ -85,34 +298,94 @@ impl<'a> IntoIterator for Step<'a> {
 impl<'a> Iterator for StepIter<'a> {
     type Item = Step<'a>;
     fn next(&mut self) -> Option<Step<'a>> {
-        let (left, right) = &self.step.network.edges[self.step.node_id];
         let direction = self.step.direction_stream.next();
 
-        self.step.node_id = match direction {
-            Direction::Left => &left,
-            Direction::Right => &right,
-        };
+        self.step.node_id = self.step.network.follow(self.step.node_id, direction);
         Some(self.step.clone())
     }
 }
 
-pub fn parse_input(input: &str) -> (Vec<Direction>, Network) {
-    let (directions_block, network_block) = input.split_once("\n\n").unwrap();
-    let directions = directions_block
-        .chars()
-        .flat_map(Direction::parse)
+// Steps are only ever compared while walking the same network.
+impl PartialEq for Step<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node_id == other.node_id && self.direction_stream == other.direction_stream
+    }
+}
+
+impl Eq for Step<'_> {}
+
+impl Hash for Step<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+pub fn parse_input(input: &str) -> ParseResult<(Vec<Direction>, Network)> {
+    let blocks = parse::blocks(input);
+    let (directions_block, network_block) = match &blocks[..] {
+        [directions_block, network_block] => (directions_block, network_block),
+        _ => {
+            return Err(ParseError::end_of_input(
+                input,
+                ParseErrorKind::Expected("a line of directions followed by the network"),
+            ))
+        }
+    };
+    let directions = parse_directions(directions_block[0])?;
+    let entries = network_block
+        .iter()
+        .map(|&line| Ok((line, parse_network_line(line)?)))
+        .collect::<ParseResult<Vec<_>>>()?;
+    let names: Vec<_> = entries
+        .iter()
+        .map(|(_, (name, _))| name.to_string())
         .collect();
-    let edges = network_block.lines().map(parse_network_line).collect();
-    let network = Network { edges };
-    (directions, network)
+    let ids: HashMap<_, _> = names
+        .iter()
+        .enumerate()
+        .map(|(node_id, name)| (name.clone(), node_id))
+        .collect();
+
+    // Every node we can move to needs its own entry, otherwise walking the network would get stuck.
+    let mut graph = AdjacencyList::new(EdgeMode::Directed);
+    for (node_id, (line, (_, (left, right)))) in entries.into_iter().enumerate() {
+        for (direction, next) in [(Direction::Left, left), (Direction::Right, right)] {
+            let next_id = ids.get(next).ok_or_else(|| {
+                line.error_at(next, ParseErrorKind::UnexpectedToken(next.to_string()))
+            })?;
+            graph.add_edge(node_id, *next_id, direction);
+        }
+    }
+
+    Ok((directions, Network { graph, names, ids }))
+}
+
+fn parse_directions(line: Line) -> ParseResult<Vec<Direction>> {
+    if line.text.is_empty() {
+        return Err(line.error_at(
+            line.text,
+            ParseErrorKind::Expected("at least one direction"),
+        ));
+    }
+    line.char_indices(line.text)
+        .map(|(offset, input)| {
+            Direction::parse(input).ok_or_else(|| line.unexpected_character(offset, input))
+        })
+        .collect()
 }
 
-fn parse_network_line(input: &str) -> (NodeID, (NodeID, NodeID)) {
-    let (name, edges) = input.split_once(" = ").unwrap();
-    let (left, right) = edges.split_once(", ").unwrap();
+fn parse_network_line(line: Line<'_>) -> ParseResult<(&str, (&str, &str))> {
+    let (name, edges) = line.split_once(line.text, " = ")?;
+    let edges = edges
+        .strip_prefix('(')
+        .and_then(|edges| edges.strip_suffix(')'))
+        .ok_or_else(|| {
+            line.error_at(
+                edges,
+                ParseErrorKind::Expected("a pair of nodes in parentheses"),
+            )
+        })?;
+    let (left, right) = line.split_once(edges, ", ")?;
 
-    (
-        name.to_string(),
-        (left[1..].to_string(), right[..right.len() - 1].to_string()),
-    )
+    Ok((name, (left, right)))
 }
@@ -1,9 +1,41 @@
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseErrorKind;
+use crate::parse::ParseResult;
+use crate::solution::Solution;
+use anyhow::Result;
+
 pub type Value = i64;
 
+#[derive(Clone)]
 pub struct History {
     values: Vec<Value>,
 }
 
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Puzzle = Vec<History>;
+    type Answer = Value;
+
+    fn parse(input: &str) -> Result<Self::Puzzle> {
+        Ok(parse_input(input)?)
+    }
+
+    // Predictions work in place, so each part runs on its own copy of the report.
+    fn part_one(report: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(report.clone().iter_mut().map(History::predict_next).sum())
+    }
+
+    fn part_two(report: &Self::Puzzle) -> Result<Self::Answer> {
+        Ok(report
+            .clone()
+            .iter_mut()
+            .map(History::predict_previous)
+            .sum())
+    }
+}
+
 impl History {
     pub fn predict_previous(&mut self) -> Value {
         let mut is_constant = false;
@@ -12,7 +44,7 @@ impl History {
         while !is_constant {
             left += 1;
             for idx in (left..self.values.len()).rev() {
-                self.values[idx] = self.values[idx] - self.values[idx - 1];
+                self.values[idx] -= self.values[idx - 1];
             }
 
             let last = self.values[self.values.len() - 1];
@@ -38,19 +70,22 @@ impl History {
             }
 
             let first = self.values[0];
-            is_constant = self.values.iter().take(right).all(|&x| x == first) || right <= 0;
+            is_constant = self.values.iter().take(right).all(|&x| x == first) || right == 0;
         }
 
         self.values.iter().skip(right - 1).sum()
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<History> {
-    input.lines().map(parse_history).collect()
+pub fn parse_input(input: &str) -> ParseResult<Vec<History>> {
+    parse::lines(input).map(parse_history).collect()
 }
 
-fn parse_history(raw_history: &str) -> History {
-    History {
-        values: raw_history.split(' ').flat_map(str::parse).collect(),
+fn parse_history(line: Line) -> ParseResult<History> {
+    let values: Vec<Value> = line.numbers(line.text)?;
+
+    if values.len() < 2 {
+        return Err(line.error_at(line.text, ParseErrorKind::Expected("at least two values")));
     }
+    Ok(History { values })
 }
@@ -5,14 +5,122 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::ops::Add;
 
+mod adjacency;
+mod all_pairs;
+mod astar;
+mod bellman_ford;
+mod compress;
+mod connectivity;
+mod dag;
+mod dial;
+mod flow;
+mod longest_path;
+mod traversal;
+mod union_find;
+
+pub use adjacency::AdjacencyList;
+pub use adjacency::AdjacencyMatrix;
+pub use adjacency::EdgeMode;
+pub use all_pairs::AllPairs;
+pub use all_pairs::DistanceTable;
+pub use astar::AStar;
+pub use astar::AStarSearch;
+pub use bellman_ford::BellmanFord;
+pub use bellman_ford::NegativeCycle;
+pub use compress::Compress;
+pub use connectivity::Connectivity;
+pub use dag::Dag;
+pub use dag::DirectedCycle;
+pub use dial::Dial;
+pub use dial::IndexedGraph;
+pub use flow::FlowNetwork;
+pub use flow::MaxFlow;
+pub use flow::MinCut;
+pub use longest_path::BudgetExhausted;
+pub use longest_path::LongestSimplePath;
+pub use traversal::Traversal;
+pub use traversal::Unweighted;
+pub use traversal::UnweightedGraph;
+pub use union_find::UnionFind;
+
+// Edge distances must not be negative, use BellmanFord for those graphs.
 pub trait Dijkstra<Node, Distance> {
     fn distance(&self, seeds: &[Node], is_goal: impl Fn(&Node) -> bool) -> Option<Distance>;
+    // The distance to every node reachable from the seeds.
+    fn distances_from(&self, seeds: &[Node]) -> HashMap<Node, Distance>;
+    fn shortest_path(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+    ) -> Option<Path<Node, Distance>>;
+    // Every path of minimal length from any seed to any goal. Edge distances must
+    // be positive, and beware that there can be exponentially many such paths.
+    fn all_shortest_paths(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+    ) -> Vec<Path<Node, Distance>>;
+    // Like distance, also counting what the search went through and calling
+    // the visitor on every node settled and every edge that improved a distance.
+    fn traced_distance(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        visitor: impl FnMut(SearchEvent<Node, Distance>),
+    ) -> (Option<Distance>, SearchStats);
 }
 
 pub trait Graph<Node, Distance> {
     fn neighbours(&self, node: &Node) -> Vec<(Distance, Node)>;
 }
 
+// The nodes go from the seed to the goal, both included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<Node, Distance> {
+    pub distance: Distance,
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchEvent<Node, Distance> {
+    // The distance to the node is final.
+    Settle {
+        node: Node,
+        distance: Distance,
+    },
+    // A shorter way to reach `to` was found, going through `from`.
+    Relax {
+        from: Node,
+        to: Node,
+        distance: Distance,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    pub popped: usize,
+    // Popped entries that had been superseded by a shorter distance to their node.
+    pub stale: usize,
+    pub max_heap_size: usize,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Tracking {
+    Nothing,
+    FirstPath,
+    AllPaths,
+}
+
+struct Search<Node, Distance> {
+    distance: Option<Distance>,
+    // Only final for the nodes that were visited, which is all of them when no goal is found.
+    cumulative_distances: HashMap<Node, Distance>,
+    goals: Vec<Node>,
+    // For each reached node, the nodes it can be reached from with minimal distance.
+    predecessors: HashMap<Node, Vec<Node>>,
+    stats: SearchStats,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 struct DijkstraDistanceNode<Node, Distance> {
     node: Node,
@@ -55,46 +163,173 @@ where
     Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
 {
     fn distance(&self, seeds: &[Node], is_goal: impl Fn(&Node) -> bool) -> Option<Distance> {
-        let mut cumulative_distances: HashMap<Node, Distance> = HashMap::new();
-        let mut unvisited: BinaryHeap<DijkstraDistanceNode<Node, Distance>> = BinaryHeap::new();
+        search(self, seeds, is_goal, Tracking::Nothing, |_| ()).distance
+    }
 
-        for seed in seeds.iter().cloned() {
-            unvisited.push(DijkstraDistanceNode::from(Default::default(), seed));
-            cumulative_distances.insert(seed, Default::default());
+    fn distances_from(&self, seeds: &[Node]) -> HashMap<Node, Distance> {
+        search(self, seeds, |_| false, Tracking::Nothing, |_| ()).cumulative_distances
+    }
+
+    fn shortest_path(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+    ) -> Option<Path<Node, Distance>> {
+        let search = search(self, seeds, is_goal, Tracking::FirstPath, |_| ());
+        let distance = search.distance?;
+        let mut nodes = vec![search.goals[0]];
+        while let Some(&previous) = search.predecessors[nodes.last()?].first() {
+            nodes.push(previous);
         }
+        nodes.reverse();
+
+        Some(Path { distance, nodes })
+    }
+
+    fn all_shortest_paths(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+    ) -> Vec<Path<Node, Distance>> {
+        let search = search(self, seeds, is_goal, Tracking::AllPaths, |_| ());
+        let Some(distance) = search.distance else {
+            return vec![];
+        };
+        let mut paths = vec![];
+        // Partial paths are built backwards, from a goal towards the seeds.
+        let mut partial_paths: Vec<Vec<Node>> =
+            search.goals.iter().map(|&goal| vec![goal]).collect();
 
-        while let Some(current) = unvisited.pop() {
-            if is_goal(&current.node) {
-                return Some(current.cumulative_distance);
+        while let Some(partial_path) = partial_paths.pop() {
+            let predecessors = &search.predecessors[partial_path.last().unwrap()];
+            if predecessors.is_empty() {
+                let nodes = partial_path.into_iter().rev().collect();
+                paths.push(Path { distance, nodes });
+            } else {
+                for &previous in predecessors {
+                    let mut extended = partial_path.clone();
+                    extended.push(previous);
+                    partial_paths.push(extended);
+                }
             }
+        }
 
-            let best_cumulative_distance = cumulative_distances[&current.node];
+        paths
+    }
 
-            if best_cumulative_distance < current.cumulative_distance {
+    fn traced_distance(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        visitor: impl FnMut(SearchEvent<Node, Distance>),
+    ) -> (Option<Distance>, SearchStats) {
+        let search = search(self, seeds, is_goal, Tracking::Nothing, visitor);
+        (search.distance, search.stats)
+    }
+}
+
+fn search<Node, Distance>(
+    graph: &impl Graph<Node, Distance>,
+    seeds: &[Node],
+    is_goal: impl Fn(&Node) -> bool,
+    tracking: Tracking,
+    mut visit: impl FnMut(SearchEvent<Node, Distance>),
+) -> Search<Node, Distance>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    let mut unvisited: BinaryHeap<DijkstraDistanceNode<Node, Distance>> = BinaryHeap::new();
+    let mut result = Search {
+        distance: None,
+        cumulative_distances: HashMap::new(),
+        goals: vec![],
+        predecessors: HashMap::new(),
+        stats: SearchStats::default(),
+    };
+
+    for seed in seeds.iter().cloned() {
+        unvisited.push(DijkstraDistanceNode::from(Default::default(), seed));
+        result.cumulative_distances.insert(seed, Default::default());
+        if tracking != Tracking::Nothing {
+            result.predecessors.insert(seed, vec![]);
+        }
+    }
+
+    result.stats.max_heap_size = unvisited.len();
+    while let Some(current) = unvisited.pop() {
+        result.stats.popped += 1;
+        let best_cumulative_distance = result.cumulative_distances[&current.node];
+
+        if best_cumulative_distance < current.cumulative_distance {
+            result.stats.stale += 1;
+            continue;
+        }
+        if result
+            .distance
+            .is_some_and(|distance| distance < current.cumulative_distance)
+        {
+            // Only reachable when looking for all the paths: every goal at the
+            // shortest distance has been found.
+            break;
+        }
+        visit(SearchEvent::Settle {
+            node: current.node,
+            distance: current.cumulative_distance,
+        });
+        if is_goal(&current.node) {
+            result.distance = Some(current.cumulative_distance);
+            result.goals.push(current.node);
+            if tracking == Tracking::AllPaths {
                 continue;
             }
-            // TODO what happens if i take two simulatenous entries?
-            for (edge_distance, neighbour) in self.neighbours(&current.node) {
-                let entry = cumulative_distances.entry(neighbour);
-                let candidate_cumulative_distance = current.cumulative_distance + edge_distance;
-                if let Entry::Occupied(mut o) = entry {
+            break;
+        }
+
+        for (edge_distance, neighbour) in graph.neighbours(&current.node) {
+            let entry = result.cumulative_distances.entry(neighbour);
+            let candidate_cumulative_distance = current.cumulative_distance + edge_distance;
+            let improved = match entry {
+                Entry::Occupied(mut o) => {
                     let best_yet = o.get_mut();
                     if *best_yet > candidate_cumulative_distance {
                         *best_yet = candidate_cumulative_distance;
-                        unvisited.push(DijkstraDistanceNode::from(
-                            candidate_cumulative_distance,
-                            neighbour,
-                        ));
+                        true
+                    } else {
+                        if *best_yet == candidate_cumulative_distance
+                            && tracking == Tracking::AllPaths
+                        {
+                            let predecessors = result.predecessors.entry(neighbour).or_default();
+                            if !predecessors.contains(&current.node) {
+                                predecessors.push(current.node);
+                            }
+                        }
+                        false
                     }
-                } else {
-                    entry.or_insert(candidate_cumulative_distance);
-                    unvisited.push(DijkstraDistanceNode::from(
-                        candidate_cumulative_distance,
-                        neighbour,
-                    ));
                 }
+                Entry::Vacant(v) => {
+                    v.insert(candidate_cumulative_distance);
+                    true
+                }
+            };
+
+            if improved {
+                visit(SearchEvent::Relax {
+                    from: current.node,
+                    to: neighbour,
+                    distance: candidate_cumulative_distance,
+                });
+                if tracking != Tracking::Nothing {
+                    result.predecessors.insert(neighbour, vec![current.node]);
+                }
+                unvisited.push(DijkstraDistanceNode::from(
+                    candidate_cumulative_distance,
+                    neighbour,
+                ));
             }
         }
-        None
+        result.stats.max_heap_size = result.stats.max_heap_size.max(unvisited.len());
     }
+
+    result
 }
@@ -0,0 +1,284 @@
+use super::Graph;
+use super::IndexedGraph;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EdgeMode {
+    Directed,
+    // Every edge can be walked both ways, as if it were a pair of directed edges.
+    Undirected,
+}
+
+// A graph that stores its edges explicitly, keyed by the node they leave from.
+#[derive(Clone, Debug)]
+pub struct AdjacencyList<Node, Distance> {
+    mode: EdgeMode,
+    edges: HashMap<Node, Vec<(Distance, Node)>>,
+}
+
+// A dense graph over the nodes 0..node_count, with at most one edge between
+// any two nodes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdjacencyMatrix<Distance> {
+    mode: EdgeMode,
+    node_count: usize,
+    distances: Vec<Option<Distance>>,
+}
+
+impl<Node, Distance> AdjacencyList<Node, Distance>
+where
+    Node: Clone + Eq + Hash + PartialEq,
+    Distance: Clone,
+{
+    pub fn new(mode: EdgeMode) -> Self {
+        Self {
+            mode,
+            edges: HashMap::new(),
+        }
+    }
+
+    // Builds the graph from (from, to, distance) triples.
+    pub fn from_edges(
+        mode: EdgeMode,
+        edges: impl IntoIterator<Item = (Node, Node, Distance)>,
+    ) -> Self {
+        let mut graph = Self::new(mode);
+        for (from, to, distance) in edges {
+            graph.add_edge(from, to, distance);
+        }
+        graph
+    }
+
+    pub fn mode(&self) -> EdgeMode {
+        self.mode
+    }
+
+    pub fn add_node(&mut self, node: Node) {
+        self.edges.entry(node).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: Node, to: Node, distance: Distance) {
+        if self.mode == EdgeMode::Undirected {
+            self.edges
+                .entry(to.clone())
+                .or_default()
+                .push((distance.clone(), from.clone()));
+        } else {
+            self.add_node(to.clone());
+        }
+        self.edges.entry(from).or_default().push((distance, to));
+    }
+
+    // Removes the node along with every edge leading to or from it.
+    pub fn remove_node(&mut self, node: &Node) -> bool {
+        let removed = self.edges.remove(node).is_some();
+        if removed {
+            for edges in self.edges.values_mut() {
+                edges.retain(|(_, to)| to != node);
+            }
+        }
+        removed
+    }
+
+    // Removes every edge between the two nodes, returning whether there was any.
+    pub fn remove_edge(&mut self, from: &Node, to: &Node) -> bool {
+        let mut removed = Self::remove_edges_between(&mut self.edges, from, to);
+        if self.mode == EdgeMode::Undirected {
+            removed |= Self::remove_edges_between(&mut self.edges, to, from);
+        }
+        removed
+    }
+
+    pub fn contains_node(&self, node: &Node) -> bool {
+        self.edges.contains_key(node)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.edges.keys()
+    }
+
+    // Every edge as (from, to, distance). Undirected edges show up once in each direction.
+    pub fn edges(&self) -> impl Iterator<Item = (&Node, &Node, &Distance)> {
+        self.edges
+            .iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |(distance, to)| (from, to, distance)))
+    }
+
+    // Like Graph::neighbours, without copying the edges.
+    pub fn edges_from(&self, node: &Node) -> &[(Distance, Node)] {
+        self.edges.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    fn remove_edges_between(
+        edges: &mut HashMap<Node, Vec<(Distance, Node)>>,
+        from: &Node,
+        to: &Node,
+    ) -> bool {
+        edges.get_mut(from).is_some_and(|edges| {
+            let count = edges.len();
+            edges.retain(|(_, neighbour)| neighbour != to);
+            edges.len() < count
+        })
+    }
+}
+
+impl<Node, Distance> Default for AdjacencyList<Node, Distance>
+where
+    Node: Clone + Eq + Hash + PartialEq,
+    Distance: Clone,
+{
+    fn default() -> Self {
+        Self::new(EdgeMode::Directed)
+    }
+}
+
+impl<Node, Distance> Graph<Node, Distance> for AdjacencyList<Node, Distance>
+where
+    Node: Clone + Eq + Hash + PartialEq,
+    Distance: Clone,
+{
+    fn neighbours(&self, node: &Node) -> Vec<(Distance, Node)> {
+        self.edges_from(node).to_vec()
+    }
+}
+
+impl<Distance: Clone> AdjacencyMatrix<Distance> {
+    pub fn new(mode: EdgeMode, node_count: usize) -> Self {
+        Self {
+            mode,
+            node_count,
+            distances: vec![None; node_count * node_count],
+        }
+    }
+
+    // Builds the graph from (from, to, distance) triples, a later edge
+    // replacing an earlier one between the same nodes.
+    pub fn from_edges(
+        mode: EdgeMode,
+        node_count: usize,
+        edges: impl IntoIterator<Item = (usize, usize, Distance)>,
+    ) -> Self {
+        let mut graph = Self::new(mode, node_count);
+        for (from, to, distance) in edges {
+            graph.add_edge(from, to, distance);
+        }
+        graph
+    }
+
+    pub fn mode(&self) -> EdgeMode {
+        self.mode
+    }
+
+    // Adds an isolated node and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        let node_count = self.node_count + 1;
+        let mut distances = vec![None; node_count * node_count];
+        for (from, row) in self.distances.chunks(self.node_count.max(1)).enumerate() {
+            distances[from * node_count..from * node_count + row.len()].clone_from_slice(row);
+        }
+        self.distances = distances;
+        self.node_count = node_count;
+        self.node_count - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, distance: Distance) {
+        let (forward, backward) = (self.slot(from, to), self.slot(to, from));
+        if self.mode == EdgeMode::Undirected {
+            self.distances[backward] = Some(distance.clone());
+        }
+        self.distances[forward] = Some(distance);
+    }
+
+    // Removes the node along with its edges, returning whether it existed. The
+    // nodes after it move down by one index to fill the gap.
+    pub fn remove_node(&mut self, node: usize) -> bool {
+        if node >= self.node_count {
+            return false;
+        }
+        let node_count = self.node_count;
+        self.distances = self
+            .distances
+            .drain(..)
+            .enumerate()
+            .filter(|(idx, _)| idx / node_count != node && idx % node_count != node)
+            .map(|(_, distance)| distance)
+            .collect();
+        self.node_count -= 1;
+        true
+    }
+
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        let (forward, backward) = (self.slot(from, to), self.slot(to, from));
+        if self.mode == EdgeMode::Undirected {
+            self.distances[backward] = None;
+        }
+        self.distances[forward].take().is_some()
+    }
+
+    pub fn edge(&self, from: usize, to: usize) -> Option<&Distance> {
+        self.distances[self.slot(from, to)].as_ref()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.node_count
+    }
+
+    // Every edge as (from, to, distance). Undirected edges show up once in each direction.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, &Distance)> {
+        let node_count = self.node_count;
+        self.distances
+            .iter()
+            .enumerate()
+            .filter_map(move |(idx, distance)| {
+                distance
+                    .as_ref()
+                    .map(|distance| (idx / node_count, idx % node_count, distance))
+            })
+    }
+
+    // Where the edge between the two nodes is stored. Both must be in the
+    // graph, or the index would wrap around into another row.
+    fn slot(&self, from: usize, to: usize) -> usize {
+        assert!(
+            from < self.node_count && to < self.node_count,
+            "edge ({from}, {to}) outside of a graph with {} nodes",
+            self.node_count
+        );
+        from * self.node_count + to
+    }
+}
+
+impl<Distance: Clone> Graph<usize, Distance> for AdjacencyMatrix<Distance> {
+    fn neighbours(&self, node: &usize) -> Vec<(Distance, usize)> {
+        assert!(
+            *node < self.node_count,
+            "node {node} outside of a graph with {} nodes",
+            self.node_count
+        );
+        self.distances[node * self.node_count..(node + 1) * self.node_count]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, distance)| distance.clone().map(|distance| (distance, to)))
+            .collect()
+    }
+}
+
+impl<Distance: Clone> IndexedGraph<usize, Distance> for AdjacencyMatrix<Distance> {
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    fn index(&self, node: &usize) -> usize {
+        *node
+    }
+}
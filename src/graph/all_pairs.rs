@@ -0,0 +1,81 @@
+use super::Dijkstra;
+use super::Graph;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+// The distance between every (from, to) pair of nodes that are connected.
+pub type DistanceTable<Node, Distance> = HashMap<(Node, Node), Distance>;
+
+pub trait AllPairs<Node, Distance> {
+    // Only follows edges between the given nodes, so they should be all the
+    // nodes of the graph. Best suited to small, dense graphs.
+    fn floyd_warshall(&self, nodes: &[Node]) -> DistanceTable<Node, Distance>;
+    // Runs Dijkstra from each of the given nodes. Paths may go through any node
+    // of the graph, which makes this suited to distances between points of interest.
+    fn repeated_dijkstra(&self, nodes: &[Node]) -> DistanceTable<Node, Distance>;
+}
+
+impl<Node, Distance, T> AllPairs<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    fn floyd_warshall(&self, nodes: &[Node]) -> DistanceTable<Node, Distance> {
+        let indices: HashMap<Node, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, &node)| (node, idx))
+            .collect();
+        let count = nodes.len();
+        let mut distances: Vec<Option<Distance>> = vec![None; count * count];
+
+        for (from, node) in nodes.iter().enumerate() {
+            distances[from * count + from] = Some(Default::default());
+            for (edge_distance, neighbour) in self.neighbours(node) {
+                if let Some(&to) = indices.get(&neighbour) {
+                    let best_yet = &mut distances[from * count + to];
+                    *best_yet = Some(best_yet.map_or(edge_distance, |d| d.min(edge_distance)));
+                }
+            }
+        }
+
+        for through in 0..count {
+            for from in 0..count {
+                let Some(first_leg) = distances[from * count + through] else {
+                    continue;
+                };
+                for to in 0..count {
+                    if let Some(second_leg) = distances[through * count + to] {
+                        let candidate = first_leg + second_leg;
+                        let best_yet = &mut distances[from * count + to];
+                        if best_yet.is_none_or(|d| candidate < d) {
+                            *best_yet = Some(candidate);
+                        }
+                    }
+                }
+            }
+        }
+
+        distances
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, distance)| {
+                distance.map(|distance| ((nodes[idx / count], nodes[idx % count]), distance))
+            })
+            .collect()
+    }
+
+    fn repeated_dijkstra(&self, nodes: &[Node]) -> DistanceTable<Node, Distance> {
+        nodes
+            .iter()
+            .flat_map(|&from| {
+                let distances = self.distances_from(&[from]);
+                nodes.iter().filter_map(move |&to| {
+                    distances.get(&to).map(|&distance| ((from, to), distance))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,131 @@
+use super::Graph;
+use super::Path;
+use std::cmp;
+use std::collections::hash_map::Entry;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait AStar<Node, Distance> {
+    // The heuristic must never overestimate the distance to the closest goal,
+    // otherwise the path found may not be the shortest one.
+    fn a_star(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        heuristic: impl Fn(&Node) -> Distance,
+    ) -> AStarSearch<Node, Distance>;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AStarSearch<Node, Distance> {
+    pub path: Option<Path<Node, Distance>>,
+    // How many nodes had their neighbours generated.
+    pub expanded: usize,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct AStarFrontierNode<Node, Distance> {
+    node: Node,
+    cumulative_distance: Distance,
+    estimate: Distance,
+}
+
+impl<Node, Distance> Ord for AStarFrontierNode<Node, Distance>
+where
+    Node: Eq + PartialEq,
+    Distance: Ord,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            // Prefer the nodes that are further along when the estimates tie.
+            .then_with(|| self.cumulative_distance.cmp(&other.cumulative_distance))
+    }
+}
+
+impl<Node, Distance> PartialOrd for AStarFrontierNode<Node, Distance>
+where
+    Node: Eq + PartialEq,
+    Distance: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Node, Distance, T> AStar<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    fn a_star(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        heuristic: impl Fn(&Node) -> Distance,
+    ) -> AStarSearch<Node, Distance> {
+        let mut cumulative_distances: HashMap<Node, Distance> = HashMap::new();
+        let mut predecessors: HashMap<Node, Node> = HashMap::new();
+        let mut unvisited: BinaryHeap<AStarFrontierNode<Node, Distance>> = BinaryHeap::new();
+        let mut expanded = 0;
+
+        for seed in seeds.iter().cloned() {
+            unvisited.push(AStarFrontierNode {
+                node: seed,
+                cumulative_distance: Default::default(),
+                estimate: heuristic(&seed),
+            });
+            cumulative_distances.insert(seed, Default::default());
+        }
+
+        while let Some(current) = unvisited.pop() {
+            if cumulative_distances[&current.node] < current.cumulative_distance {
+                continue;
+            }
+            if is_goal(&current.node) {
+                let mut nodes = vec![current.node];
+                while let Some(previous) = predecessors.get(nodes.last().unwrap()) {
+                    nodes.push(*previous);
+                }
+                nodes.reverse();
+
+                return AStarSearch {
+                    path: Some(Path {
+                        distance: current.cumulative_distance,
+                        nodes,
+                    }),
+                    expanded,
+                };
+            }
+
+            expanded += 1;
+            for (edge_distance, neighbour) in self.neighbours(&current.node) {
+                let candidate_cumulative_distance = current.cumulative_distance + edge_distance;
+                match cumulative_distances.entry(neighbour) {
+                    Entry::Occupied(o) if *o.get() <= candidate_cumulative_distance => continue,
+                    Entry::Occupied(mut o) => {
+                        o.insert(candidate_cumulative_distance);
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert(candidate_cumulative_distance);
+                    }
+                }
+                predecessors.insert(neighbour, current.node);
+                unvisited.push(AStarFrontierNode {
+                    node: neighbour,
+                    cumulative_distance: candidate_cumulative_distance,
+                    estimate: candidate_cumulative_distance + heuristic(&neighbour),
+                });
+            }
+        }
+
+        AStarSearch {
+            path: None,
+            expanded,
+        }
+    }
+}
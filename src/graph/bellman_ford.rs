@@ -0,0 +1,109 @@
+use super::Graph;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait BellmanFord<Node, Distance> {
+    // The distance to every node reachable from the seeds, which unlike
+    // Dijkstra copes with negative edges.
+    fn bellman_ford(&self, seeds: &[Node]) -> Result<HashMap<Node, Distance>, NegativeCycle<Node>>;
+}
+
+// A cycle reachable from the seeds whose edges add up to a negative distance,
+// so that shortest distances are unbounded. The nodes are listed in edge order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NegativeCycle<Node> {
+    pub nodes: Vec<Node>,
+}
+
+// This is the queue based variant of the algorithm (SPFA), which only
+// relaxes the edges of nodes whose distance just improved.
+impl<Node, Distance, T> BellmanFord<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    fn bellman_ford(&self, seeds: &[Node]) -> Result<HashMap<Node, Distance>, NegativeCycle<Node>> {
+        let mut distances: HashMap<Node, Distance> = HashMap::new();
+        let mut predecessors: HashMap<Node, Node> = HashMap::new();
+        // How many edges the current best path to each node has.
+        let mut edge_counts: HashMap<Node, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        let mut queued = HashSet::new();
+
+        for &seed in seeds {
+            distances.insert(seed, Default::default());
+            edge_counts.insert(seed, 0);
+            if queued.insert(seed) {
+                queue.push_back(seed);
+            }
+        }
+
+        while let Some(current) = queue.pop_front() {
+            queued.remove(&current);
+            let current_distance = distances[&current];
+
+            for (edge_distance, neighbour) in self.neighbours(&current) {
+                let candidate_distance = current_distance + edge_distance;
+                if distances
+                    .get(&neighbour)
+                    .is_some_and(|&best_yet| best_yet <= candidate_distance)
+                {
+                    continue;
+                }
+
+                distances.insert(neighbour, candidate_distance);
+                predecessors.insert(neighbour, current);
+                let edge_count = edge_counts[&current] + 1;
+                edge_counts.insert(neighbour, edge_count);
+
+                // A simple path cannot have as many edges as there are nodes,
+                // so the predecessors have probably looped back on themselves.
+                if edge_count >= distances.len() {
+                    if let Some(nodes) = find_cycle(&predecessors, neighbour) {
+                        return Err(NegativeCycle { nodes });
+                    }
+                }
+                if queued.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+// Any cycle among the predecessors is a negative one.
+fn find_cycle<Node>(predecessors: &HashMap<Node, Node>, start: Node) -> Option<Vec<Node>>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    let mut positions: HashMap<Node, usize> = HashMap::new();
+    let mut backwards = vec![];
+    let mut current = start;
+
+    loop {
+        if let Some(&position) = positions.get(&current) {
+            let mut nodes = backwards.split_off(position);
+            nodes.reverse();
+            return Some(nodes);
+        }
+        positions.insert(current, backwards.len());
+        backwards.push(current);
+        current = *predecessors.get(&current)?;
+    }
+}
+
+impl<Node: fmt::Debug> fmt::Display for NegativeCycle<Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "negative cycle through {:?}", self.nodes)
+    }
+}
+
+impl<Node: fmt::Debug> Error for NegativeCycle<Node> {}
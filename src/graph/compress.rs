@@ -0,0 +1,106 @@
+use super::AdjacencyList;
+use super::EdgeMode;
+use super::Graph;
+use super::Traversal;
+use super::Unweighted;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait Compress<Node, Distance> {
+    // Collapses every chain of nodes with exactly two neighbours into a single
+    // weighted edge. The interesting nodes are always kept, and only the part of
+    // the graph reachable from them ends up in the result.
+    fn compress(&self, interesting: &[Node]) -> AdjacencyList<Node, Distance>;
+}
+
+// A contracted chain of corridor nodes, leading to the target junction.
+pub(super) struct Corridor<Node, Distance> {
+    pub distance: Distance,
+    pub via: Vec<Node>,
+    pub target: Node,
+}
+
+impl<Node, Distance, T> Compress<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy,
+{
+    fn compress(&self, interesting: &[Node]) -> AdjacencyList<Node, Distance> {
+        let interesting_nodes: HashSet<Node> = interesting.iter().cloned().collect();
+        let corridors =
+            contract_corridors(self, interesting, |node| interesting_nodes.contains(node));
+        let mut compressed = AdjacencyList::new(EdgeMode::Directed);
+
+        for (junction, corridors) in corridors {
+            compressed.add_node(junction);
+            for corridor in corridors {
+                compressed.add_edge(junction, corridor.target, corridor.distance);
+            }
+        }
+
+        compressed
+    }
+}
+
+// Finds the corridors leaving every junction reachable from the seeds, where a
+// junction is any node that is kept or does not have exactly two neighbours.
+pub(super) fn contract_corridors<Node, Distance>(
+    graph: &impl Graph<Node, Distance>,
+    seeds: &[Node],
+    is_kept: impl Fn(&Node) -> bool,
+) -> HashMap<Node, Vec<Corridor<Node, Distance>>>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy,
+{
+    let reachable = Unweighted::new(graph).reachable(seeds);
+    // Edges count in both directions when telling corridors apart.
+    let mut adjacent: HashMap<Node, HashSet<Node>> = HashMap::new();
+    for &node in reachable.iter() {
+        for (_, neighbour) in graph.neighbours(&node) {
+            adjacent.entry(node).or_default().insert(neighbour);
+            adjacent.entry(neighbour).or_default().insert(node);
+        }
+    }
+    let is_corridor =
+        |node: &Node| !is_kept(node) && adjacent.get(node).is_some_and(|a| a.len() == 2);
+
+    let mut corridors: HashMap<Node, Vec<Corridor<Node, Distance>>> = HashMap::new();
+    for &junction in reachable.iter().filter(|node| !is_corridor(node)) {
+        corridors.entry(junction).or_default();
+        'edges: for (edge_distance, first) in graph.neighbours(&junction) {
+            let mut distance = edge_distance;
+            let mut via = vec![];
+            let (mut previous, mut current) = (junction, first);
+
+            while is_corridor(&current) {
+                via.push(current);
+                let next = graph
+                    .neighbours(&current)
+                    .into_iter()
+                    .find(|&(_, neighbour)| neighbour != previous);
+                match next {
+                    // Corridors that loop onto themselves never reach a junction.
+                    Some(_) if via.len() > reachable.len() => continue 'edges,
+                    Some((edge_distance, next)) => {
+                        distance = distance + edge_distance;
+                        (previous, current) = (current, next);
+                    }
+                    // A one-way corridor leading back where it came from.
+                    None => continue 'edges,
+                }
+            }
+
+            corridors.entry(junction).or_default().push(Corridor {
+                distance,
+                via,
+                target: current,
+            });
+        }
+    }
+
+    corridors
+}
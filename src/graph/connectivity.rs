@@ -0,0 +1,234 @@
+use super::traversal::successors_to_pop;
+use super::Traversal;
+use super::UnweightedGraph;
+use std::cmp;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// Only the nodes reachable from the seeds are considered. Bridges, articulation
+// points and connected components assume an undirected graph, i.e. that every
+// edge is listed from both ends.
+pub trait Connectivity<Node> {
+    // Every component can reach every node within it, and none outside of it
+    // that can reach it back. Components come in reverse topological order.
+    fn strongly_connected_components(&self, seeds: &[Node]) -> Vec<Vec<Node>>;
+    fn connected_components(&self, seeds: &[Node]) -> Vec<Vec<Node>>;
+    // The edges whose removal would disconnect their ends.
+    fn bridges(&self, seeds: &[Node]) -> Vec<(Node, Node)>;
+    // The nodes whose removal would disconnect their component.
+    fn articulation_points(&self, seeds: &[Node]) -> Vec<Node>;
+}
+
+// Tarjan's algorithm, with an explicit stack so that long paths don't overflow.
+struct Tarjan<Node> {
+    indices: HashMap<Node, usize>,
+    low_links: HashMap<Node, usize>,
+    stack: Vec<Node>,
+    on_stack: HashSet<Node>,
+    components: Vec<Vec<Node>>,
+}
+
+// The depth-first search forest of an undirected graph, where the low link of a
+// node is the earliest discovered node reachable from its subtree through at
+// most one back edge.
+struct LowLinks<Node> {
+    discovery: HashMap<Node, usize>,
+    low_links: HashMap<Node, usize>,
+    roots: Vec<Node>,
+    tree_edges: Vec<(Node, Node)>,
+}
+
+impl<Node, T> Connectivity<Node> for T
+where
+    T: UnweightedGraph<Node>,
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    fn strongly_connected_components(&self, seeds: &[Node]) -> Vec<Vec<Node>> {
+        let mut tarjan = Tarjan {
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: vec![],
+            on_stack: HashSet::new(),
+            components: vec![],
+        };
+        for &seed in seeds {
+            if !tarjan.indices.contains_key(&seed) {
+                tarjan.explore(self, seed);
+            }
+        }
+        tarjan.components
+    }
+
+    fn connected_components(&self, seeds: &[Node]) -> Vec<Vec<Node>> {
+        let mut assigned = HashSet::new();
+        let mut components = vec![];
+        for &seed in seeds {
+            if !assigned.contains(&seed) {
+                let component = self.breadth_first(&[seed]);
+                assigned.extend(component.iter().cloned());
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    fn bridges(&self, seeds: &[Node]) -> Vec<(Node, Node)> {
+        let low_links = LowLinks::from(self, seeds);
+        low_links
+            .tree_edges
+            .iter()
+            .filter(|(parent, child)| low_links.low_links[child] > low_links.discovery[parent])
+            .cloned()
+            .collect()
+    }
+
+    fn articulation_points(&self, seeds: &[Node]) -> Vec<Node> {
+        let low_links = LowLinks::from(self, seeds);
+        let roots: HashSet<Node> = low_links.roots.iter().cloned().collect();
+        let mut children: HashMap<Node, usize> = HashMap::new();
+        let mut found = HashSet::new();
+        let mut points = vec![];
+        for (parent, child) in &low_links.tree_edges {
+            *children.entry(*parent).or_default() += 1;
+            if !roots.contains(parent)
+                && low_links.low_links[child] >= low_links.discovery[parent]
+                && found.insert(*parent)
+            {
+                points.push(*parent);
+            }
+        }
+        // A root only holds its component together when it has several subtrees.
+        points.extend(
+            low_links
+                .roots
+                .iter()
+                .filter(|root| children.get(root).is_some_and(|&count| count > 1)),
+        );
+        points
+    }
+}
+
+impl<Node> Tarjan<Node>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    fn discover(&mut self, node: Node) {
+        let index = self.indices.len();
+        self.indices.insert(node, index);
+        self.low_links.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+    }
+
+    fn explore(&mut self, graph: &impl UnweightedGraph<Node>, seed: Node) {
+        self.discover(seed);
+        // The depth-first search path, with the successors left to explore.
+        let mut path = vec![(seed, successors_to_pop(graph, &seed))];
+
+        while let Some((node, pending)) = path.last_mut() {
+            let node = *node;
+            match pending.pop() {
+                Some(next) if !self.indices.contains_key(&next) => {
+                    self.discover(next);
+                    path.push((next, successors_to_pop(graph, &next)));
+                }
+                Some(next) => {
+                    if self.on_stack.contains(&next) {
+                        self.lower(node, self.indices[&next]);
+                    }
+                }
+                None => {
+                    path.pop();
+                    let low_link = self.low_links[&node];
+                    if let Some(&(parent, _)) = path.last() {
+                        self.lower(parent, low_link);
+                    }
+                    if low_link == self.indices[&node] {
+                        self.pop_component(node);
+                    }
+                }
+            }
+        }
+    }
+
+    fn lower(&mut self, node: Node, candidate: usize) {
+        let low_link = self.low_links.get_mut(&node).unwrap();
+        *low_link = cmp::min(*low_link, candidate);
+    }
+
+    fn pop_component(&mut self, root: Node) {
+        let mut component = vec![];
+        while let Some(node) = self.stack.pop() {
+            self.on_stack.remove(&node);
+            component.push(node);
+            if node == root {
+                break;
+            }
+        }
+        self.components.push(component);
+    }
+}
+
+impl<Node> LowLinks<Node>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    fn from(graph: &impl UnweightedGraph<Node>, seeds: &[Node]) -> Self {
+        let mut result = Self {
+            discovery: HashMap::new(),
+            low_links: HashMap::new(),
+            roots: vec![],
+            tree_edges: vec![],
+        };
+
+        for &seed in seeds {
+            if result.discovery.contains_key(&seed) {
+                continue;
+            }
+            result.discover(seed);
+            result.roots.push(seed);
+            // Besides the pending successors, each entry records its parent and
+            // whether the edge back to it has been skipped already, so that
+            // parallel edges still count as a cycle.
+            let mut path = vec![(seed, None, successors_to_pop(graph, &seed), false)];
+
+            while let Some((node, parent, pending, skipped_parent)) = path.last_mut() {
+                let node = *node;
+                match pending.pop() {
+                    Some(next) if Some(next) == *parent && !*skipped_parent => {
+                        *skipped_parent = true;
+                    }
+                    Some(next) => match result.discovery.get(&next) {
+                        Some(&discovery) => result.lower(node, discovery),
+                        None => {
+                            result.discover(next);
+                            result.tree_edges.push((node, next));
+                            path.push((next, Some(node), successors_to_pop(graph, &next), false));
+                        }
+                    },
+                    None => {
+                        let parent = *parent;
+                        path.pop();
+                        if let Some(parent) = parent {
+                            result.lower(parent, result.low_links[&node]);
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn discover(&mut self, node: Node) {
+        let discovery = self.discovery.len();
+        self.discovery.insert(node, discovery);
+        self.low_links.insert(node, discovery);
+    }
+
+    fn lower(&mut self, node: Node, candidate: usize) {
+        let low_link = self.low_links.get_mut(&node).unwrap();
+        *low_link = cmp::min(*low_link, candidate);
+    }
+}
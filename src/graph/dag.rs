@@ -0,0 +1,114 @@
+use super::traversal::successors_to_pop;
+use super::Graph;
+use super::Unweighted;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait Dag<Node, Distance> {
+    // Orders the nodes reachable from the seeds so that every edge points forward.
+    fn topological_sort(&self, seeds: &[Node]) -> Result<Vec<Node>, DirectedCycle<Node>>;
+    // The longest distance from any seed to every reachable node.
+    fn longest_distances(
+        &self,
+        seeds: &[Node],
+    ) -> Result<HashMap<Node, Distance>, DirectedCycle<Node>>;
+}
+
+// A cycle that prevents the graph from being a DAG, with the nodes listed in edge order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirectedCycle<Node> {
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Mark {
+    InProgress,
+    Done,
+}
+
+impl<Node, Distance, T> Dag<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    fn topological_sort(&self, seeds: &[Node]) -> Result<Vec<Node>, DirectedCycle<Node>> {
+        let graph = Unweighted::<_, Distance>::new(self);
+        let mut marks: HashMap<Node, Mark> = HashMap::new();
+        let mut postorder = vec![];
+
+        for &seed in seeds {
+            if marks.contains_key(&seed) {
+                continue;
+            }
+            marks.insert(seed, Mark::InProgress);
+            // The depth-first search path, with the successors left to explore.
+            let mut stack = vec![(seed, successors_to_pop(&graph, &seed))];
+
+            while let Some((node, pending)) = stack.last_mut() {
+                let node = *node;
+                match pending.pop() {
+                    Some(next) => match marks.get(&next) {
+                        Some(Mark::InProgress) => {
+                            let start = stack.iter().position(|&(node, _)| node == next);
+                            let nodes = stack[start.unwrap()..]
+                                .iter()
+                                .map(|&(node, _)| node)
+                                .collect();
+                            return Err(DirectedCycle { nodes });
+                        }
+                        Some(Mark::Done) => (),
+                        None => {
+                            marks.insert(next, Mark::InProgress);
+                            stack.push((next, successors_to_pop(&graph, &next)));
+                        }
+                    },
+                    None => {
+                        marks.insert(node, Mark::Done);
+                        postorder.push(node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        postorder.reverse();
+        Ok(postorder)
+    }
+
+    fn longest_distances(
+        &self,
+        seeds: &[Node],
+    ) -> Result<HashMap<Node, Distance>, DirectedCycle<Node>> {
+        let mut distances: HashMap<Node, Distance> = seeds
+            .iter()
+            .map(|&seed| (seed, Default::default()))
+            .collect();
+
+        // Every node is reached from an earlier one, so its distance is final
+        // by the time it comes up.
+        for node in self.topological_sort(seeds)? {
+            let distance = distances[&node];
+            for (edge_distance, neighbour) in self.neighbours(&node) {
+                let candidate = distance + edge_distance;
+                distances
+                    .entry(neighbour)
+                    .and_modify(|best_yet| *best_yet = (*best_yet).max(candidate))
+                    .or_insert(candidate);
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+impl<Node: fmt::Debug> fmt::Display for DirectedCycle<Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cycle through {:?}", self.nodes)
+    }
+}
+
+impl<Node: fmt::Debug> Error for DirectedCycle<Node> {}
@@ -0,0 +1,130 @@
+use super::Graph;
+use num::ToPrimitive;
+use num::Unsigned;
+
+// A graph whose nodes can be numbered densely from 0 up to node_count, so that
+// per-node data can live in a Vec rather than a HashMap.
+pub trait IndexedGraph<Node, Distance>: Graph<Node, Distance> {
+    fn node_count(&self) -> usize;
+    fn index(&self, node: &Node) -> usize;
+}
+
+pub trait Dial<Node, Distance> {
+    // Dijkstra with buckets of nodes by distance instead of a heap, which pays
+    // off when edge distances are small integers. Pending nodes are never more
+    // than the longest edge past the current distance, so one bucket more than
+    // the longest edge seen is enough, reused in a circle.
+    fn dial_distance(&self, seeds: &[Node], is_goal: impl Fn(&Node) -> bool) -> Option<Distance>;
+    // A* on the same buckets, which hold nodes by distance plus heuristic
+    // instead. The heuristic must be consistent, never dropping by more than
+    // the edge just walked, so that the estimates never go back down.
+    fn dial_distance_with(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        heuristic: impl Fn(&Node) -> Distance,
+    ) -> Option<Distance>;
+}
+
+// A pending node along with the distance it was reached at, in the bucket of
+// its estimate.
+type Bucket<Node, Distance> = Vec<(Distance, Distance, Node)>;
+
+impl<Node, Distance, T> Dial<Node, Distance> for T
+where
+    T: IndexedGraph<Node, Distance>,
+    Node: Copy,
+    Distance: Copy + Ord + ToPrimitive + Unsigned,
+{
+    fn dial_distance(&self, seeds: &[Node], is_goal: impl Fn(&Node) -> bool) -> Option<Distance> {
+        self.dial_distance_with(seeds, is_goal, |_| Distance::zero())
+    }
+
+    fn dial_distance_with(
+        &self,
+        seeds: &[Node],
+        is_goal: impl Fn(&Node) -> bool,
+        heuristic: impl Fn(&Node) -> Distance,
+    ) -> Option<Distance> {
+        let to_usize = |distance: Distance| distance.to_usize().expect("distances fit in a usize");
+        let mut distances: Vec<Option<Distance>> = vec![None; self.node_count()];
+        let mut buckets: Vec<Bucket<Node, Distance>> = vec![vec![]];
+        let mut pending = 0;
+
+        let estimates = seeds.iter().map(|seed| to_usize(heuristic(seed)));
+        let mut current = estimates.min().unwrap_or(0);
+        for &seed in seeds {
+            distances[self.index(&seed)] = Some(Distance::zero());
+            let estimate = heuristic(&seed);
+            push(
+                &mut buckets,
+                current,
+                (estimate, Distance::zero(), seed),
+                to_usize,
+            );
+            pending += 1;
+        }
+
+        while pending > 0 {
+            // Zero distance edges can add to the bucket while it is being emptied.
+            loop {
+                // Growing the circle moves the nodes between buckets.
+                let bucket = current % buckets.len();
+                let Some((_, distance, node)) = buckets[bucket].pop() else {
+                    break;
+                };
+                pending -= 1;
+                if distances[self.index(&node)] != Some(distance) {
+                    // Stale, the node was reached more cheaply since.
+                    continue;
+                }
+                if is_goal(&node) {
+                    return Some(distance);
+                }
+
+                for (edge_distance, neighbour) in self.neighbours(&node) {
+                    let candidate = distance + edge_distance;
+                    let best_yet = &mut distances[self.index(&neighbour)];
+                    if best_yet.is_none_or(|best_yet| candidate < best_yet) {
+                        *best_yet = Some(candidate);
+                        let estimate = candidate + heuristic(&neighbour);
+                        push(
+                            &mut buckets,
+                            current,
+                            (estimate, candidate, neighbour),
+                            to_usize,
+                        );
+                        pending += 1;
+                    }
+                }
+            }
+            current += 1;
+        }
+
+        None
+    }
+}
+
+// Adds the entry to the bucket of its estimate, first growing the circle if
+// the estimate is too far past the current one to fit in.
+fn push<Node, Distance: Copy>(
+    buckets: &mut Vec<Bucket<Node, Distance>>,
+    current: usize,
+    entry: (Distance, Distance, Node),
+    to_usize: impl Fn(Distance) -> usize,
+) {
+    let estimate = to_usize(entry.0);
+    let ahead = estimate
+        .checked_sub(current)
+        .expect("the heuristic is consistent");
+    if ahead >= buckets.len() {
+        let count = ahead + 1;
+        let mut spread: Vec<Bucket<Node, Distance>> = (0..count).map(|_| vec![]).collect();
+        for entry in buckets.drain(..).flatten() {
+            spread[to_usize(entry.0) % count].push(entry);
+        }
+        *buckets = spread;
+    }
+    let count = buckets.len();
+    buckets[estimate % count].push(entry);
+}
@@ -0,0 +1,283 @@
+use super::Graph;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Add;
+use std::ops::Sub;
+
+// A snapshot of the nodes reachable from some seeds, with the edge distances
+// read as capacities. Nodes are indexed in the order they are discovered.
+#[derive(Clone, Debug)]
+pub struct FlowNetwork<Node, Capacity> {
+    nodes: Vec<Node>,
+    indices: HashMap<Node, usize>,
+    // Every edge is stored next to its residual twin, so edge ^ 1 walks it backwards.
+    edges: Vec<FlowEdge<Capacity>>,
+    outgoing: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Debug)]
+struct FlowEdge<Capacity> {
+    from: usize,
+    to: usize,
+    capacity: Capacity,
+}
+
+#[derive(Clone, Debug)]
+pub struct MaxFlow<Node, Capacity> {
+    pub value: Capacity,
+    // The flow through every edge that carries any, as (from, to, flow).
+    pub flows: Vec<(Node, Node, Capacity)>,
+    pub cut: MinCut<Node, Capacity>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MinCut<Node, Capacity> {
+    pub capacity: Capacity,
+    // The nodes on one side of the cut, which holds the source for s-t cuts.
+    pub side: HashSet<Node>,
+    // The edges leaving that side.
+    pub edges: Vec<(Node, Node)>,
+}
+
+impl<Node, Capacity> FlowNetwork<Node, Capacity>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Copy + Default + Ord,
+{
+    pub fn from_graph(graph: &impl Graph<Node, Capacity>, seeds: &[Node]) -> Self {
+        let mut network = Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            edges: vec![],
+            outgoing: vec![],
+        };
+        for &seed in seeds {
+            network.index(seed);
+        }
+
+        let mut next = 0;
+        while next < network.nodes.len() {
+            let node = network.nodes[next];
+            for (capacity, neighbour) in graph.neighbours(&node) {
+                let neighbour = network.index(neighbour);
+                network.add_edge(next, neighbour, capacity);
+            }
+            next += 1;
+        }
+
+        network
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    // Edmonds-Karp, i.e. augmenting along shortest paths. The source must be
+    // one of the network's nodes.
+    pub fn max_flow(&self, source: Node, sink: Node) -> MaxFlow<Node, Capacity> {
+        let zero = Capacity::default();
+        let mut residual: Vec<_> = self.edges.iter().map(|edge| edge.capacity).collect();
+        let mut value = zero;
+        let source = self.indices[&source];
+        let sink = self.indices.get(&sink).copied();
+
+        let reached = loop {
+            let parents = self.augmenting_paths(source, &residual);
+            let Some(&Some(last)) = sink.and_then(|sink| parents.get(&sink)) else {
+                break parents;
+            };
+
+            let mut path = vec![last];
+            while let Some(&Some(edge)) = parents.get(&self.edges[*path.last().unwrap()].from) {
+                path.push(edge);
+            }
+            let bottleneck = path.iter().map(|&edge| residual[edge]).min().unwrap();
+            for edge in path {
+                residual[edge] = residual[edge] - bottleneck;
+                residual[edge ^ 1] = residual[edge ^ 1] + bottleneck;
+            }
+            value = value + bottleneck;
+        };
+
+        let flows = self
+            .edges
+            .iter()
+            .zip(&residual)
+            .step_by(2)
+            .filter(|(edge, &left)| left < edge.capacity)
+            .map(|(edge, &left)| {
+                let (from, to) = (self.nodes[edge.from], self.nodes[edge.to]);
+                (from, to, edge.capacity - left)
+            })
+            .collect();
+        let side: HashSet<usize> = reached.into_keys().collect();
+
+        MaxFlow {
+            value,
+            flows,
+            cut: self.cut(&side),
+        }
+    }
+
+    // Stoer-Wagner, which expects an undirected graph with every edge listed
+    // from both ends. None if there are fewer than two nodes to separate.
+    pub fn global_min_cut(&self) -> Option<MinCut<Node, Capacity>> {
+        let zero = Capacity::default();
+        let mut weights: Vec<HashMap<usize, Capacity>> = vec![HashMap::new(); self.nodes.len()];
+        for edge in self.edges.iter().step_by(2) {
+            if edge.from != edge.to {
+                let weight = weights[edge.from].entry(edge.to).or_insert(zero);
+                *weight = *weight + edge.capacity;
+            }
+        }
+        // The original nodes merged into each remaining one.
+        let mut members: Vec<Vec<usize>> = (0..self.nodes.len()).map(|node| vec![node]).collect();
+        let mut remaining: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut best: Option<(Capacity, Vec<usize>)> = None;
+
+        while remaining.len() > 1 {
+            let (s, t, cut_of_phase) = minimum_cut_phase(&weights, &remaining);
+            if best
+                .as_ref()
+                .is_none_or(|(weight, _)| cut_of_phase < *weight)
+            {
+                best = Some((cut_of_phase, members[t].clone()));
+            }
+
+            // Merge t into s.
+            for (neighbour, weight) in std::mem::take(&mut weights[t]) {
+                weights[neighbour].remove(&t);
+                if neighbour != s {
+                    for (from, to) in [(s, neighbour), (neighbour, s)] {
+                        let merged = weights[from].entry(to).or_insert(zero);
+                        *merged = *merged + weight;
+                    }
+                }
+            }
+            let merged = std::mem::take(&mut members[t]);
+            members[s].extend(merged);
+            remaining.retain(|&node| node != t);
+        }
+
+        best.map(|(_, side)| self.cut(&side.into_iter().collect()))
+    }
+
+    fn index(&mut self, node: Node) -> usize {
+        *self.indices.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.outgoing.push(vec![]);
+            self.nodes.len() - 1
+        })
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, capacity: Capacity) {
+        self.outgoing[from].push(self.edges.len());
+        self.edges.push(FlowEdge { from, to, capacity });
+        self.outgoing[to].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            from: to,
+            to: from,
+            capacity: Capacity::default(),
+        });
+    }
+
+    // Breadth-first search over the edges with residual capacity left, mapping
+    // every reached node to the edge it was reached through.
+    fn augmenting_paths(
+        &self,
+        source: usize,
+        residual: &[Capacity],
+    ) -> HashMap<usize, Option<usize>> {
+        let mut parents = HashMap::from([(source, None)]);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &edge in &self.outgoing[node] {
+                let to = self.edges[edge].to;
+                if residual[edge] > Capacity::default() && !parents.contains_key(&to) {
+                    parents.insert(to, Some(edge));
+                    queue.push_back(to);
+                }
+            }
+        }
+        parents
+    }
+
+    fn cut(&self, side: &HashSet<usize>) -> MinCut<Node, Capacity> {
+        let crossing: Vec<_> = self
+            .edges
+            .iter()
+            .step_by(2)
+            .filter(|edge| side.contains(&edge.from) && !side.contains(&edge.to))
+            .collect();
+
+        MinCut {
+            capacity: crossing
+                .iter()
+                .fold(Capacity::default(), |total, edge| total + edge.capacity),
+            side: side.iter().map(|&node| self.nodes[node]).collect(),
+            edges: crossing
+                .iter()
+                .map(|edge| (self.nodes[edge.from], self.nodes[edge.to]))
+                .collect(),
+        }
+    }
+}
+
+impl<Node, Capacity> Graph<Node, Capacity> for FlowNetwork<Node, Capacity>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+    Capacity: Copy,
+{
+    fn neighbours(&self, node: &Node) -> Vec<(Capacity, Node)> {
+        self.indices.get(node).map_or(vec![], |&node| {
+            self.outgoing[node]
+                .iter()
+                .filter(|&&edge| edge % 2 == 0)
+                .map(|&edge| (self.edges[edge].capacity, self.nodes[self.edges[edge].to]))
+                .collect()
+        })
+    }
+}
+
+// Grows a set from an arbitrary node, always adding the node most tightly
+// connected to it. The last two nodes added are returned, along with the
+// weight of the cut that separates the very last one from everything else.
+fn minimum_cut_phase<Capacity>(
+    weights: &[HashMap<usize, Capacity>],
+    remaining: &[usize],
+) -> (usize, usize, Capacity)
+where
+    Capacity: Add<Output = Capacity> + Copy + Default + Ord,
+{
+    let mut connectivity: HashMap<usize, Capacity> = remaining
+        .iter()
+        .map(|&node| (node, Capacity::default()))
+        .collect();
+    let mut queue: BinaryHeap<(Capacity, usize)> = remaining
+        .iter()
+        .map(|&node| (Capacity::default(), node))
+        .collect();
+    let mut added = HashSet::new();
+    let (mut previous, mut last) = (remaining[0], remaining[0]);
+    let mut cut_of_phase = Capacity::default();
+
+    while let Some((weight, node)) = queue.pop() {
+        if added.contains(&node) || weight < connectivity[&node] {
+            continue;
+        }
+        added.insert(node);
+        (previous, last, cut_of_phase) = (last, node, weight);
+        for (&neighbour, &edge) in &weights[node] {
+            if !added.contains(&neighbour) {
+                let total = connectivity.get_mut(&neighbour).unwrap();
+                *total = *total + edge;
+                queue.push((*total, neighbour));
+            }
+        }
+    }
+
+    (previous, last, cut_of_phase)
+}
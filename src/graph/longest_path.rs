@@ -0,0 +1,127 @@
+use super::compress::contract_corridors;
+use super::compress::Corridor;
+use super::Graph;
+use super::Path;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait LongestSimplePath<Node, Distance> {
+    // Tries every simple path from the start to a goal, which takes exponential
+    // time, so the search gives up after extending partial paths `budget` times.
+    // Corridors, i.e. nodes with exactly two neighbours, are contracted into
+    // single edges beforehand, which only leaves the junctions to branch on.
+    fn longest_simple_path(
+        &self,
+        start: Node,
+        is_goal: impl Fn(&Node) -> bool,
+        budget: usize,
+    ) -> Result<Option<Path<Node, Distance>>, BudgetExhausted>;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BudgetExhausted {
+    pub budget: usize,
+}
+
+struct Explorer<'a, Node, Distance, G> {
+    corridors: &'a HashMap<Node, Vec<Corridor<Node, Distance>>>,
+    is_goal: G,
+    visited: HashSet<Node>,
+    taken: Vec<&'a Corridor<Node, Distance>>,
+    longest: Option<(Distance, Vec<&'a Corridor<Node, Distance>>)>,
+    steps: usize,
+    budget: usize,
+}
+
+impl<Node, Distance, T> LongestSimplePath<Node, Distance> for T
+where
+    T: Graph<Node, Distance>,
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Default + Ord + PartialOrd,
+{
+    fn longest_simple_path(
+        &self,
+        start: Node,
+        is_goal: impl Fn(&Node) -> bool,
+        budget: usize,
+    ) -> Result<Option<Path<Node, Distance>>, BudgetExhausted> {
+        let corridors = contract_corridors(self, &[start], |node| *node == start || is_goal(node));
+        let mut explorer = Explorer {
+            corridors: &corridors,
+            is_goal,
+            visited: HashSet::from([start]),
+            taken: vec![],
+            longest: None,
+            steps: 0,
+            budget,
+        };
+        explorer.explore(start, Default::default())?;
+
+        Ok(explorer.longest.map(|(distance, taken)| {
+            let mut nodes = vec![start];
+            for corridor in taken {
+                nodes.extend(corridor.via.iter().cloned());
+                nodes.push(corridor.target);
+            }
+            Path { distance, nodes }
+        }))
+    }
+}
+
+impl<'a, Node, Distance, G> Explorer<'a, Node, Distance, G>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+    Distance: Add<Output = Distance> + Copy + Ord,
+    G: Fn(&Node) -> bool,
+{
+    fn explore(&mut self, node: Node, distance: Distance) -> Result<(), BudgetExhausted> {
+        if (self.is_goal)(&node) {
+            if self
+                .longest
+                .as_ref()
+                .is_none_or(|(longest, _)| *longest < distance)
+            {
+                self.longest = Some((distance, self.taken.clone()));
+            }
+            return Ok(());
+        }
+
+        let corridors = self.corridors;
+        for corridor in corridors.get(&node).into_iter().flatten() {
+            if self.visited.contains(&corridor.target) {
+                continue;
+            }
+            if self.steps == self.budget {
+                return Err(BudgetExhausted {
+                    budget: self.budget,
+                });
+            }
+            self.steps += 1;
+
+            self.visited.insert(corridor.target);
+            self.taken.push(corridor);
+            let explored = self.explore(corridor.target, distance + corridor.distance);
+            self.taken.pop();
+            self.visited.remove(&corridor.target);
+            explored?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "gave up on the longest path search after {} steps",
+            self.budget
+        )
+    }
+}
+
+impl Error for BudgetExhausted {}
@@ -0,0 +1,141 @@
+use super::Graph;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+// For graphs where only the connections matter, not how long the edges are.
+pub trait UnweightedGraph<Node> {
+    fn successors(&self, node: &Node) -> Vec<Node>;
+}
+
+// Views a weighted graph as an unweighted one by ignoring the edge distances.
+pub struct Unweighted<'a, G, Distance> {
+    graph: &'a G,
+    distance: PhantomData<Distance>,
+}
+
+pub trait Traversal<Node> {
+    // The nodes in the order a breadth-first search visits them.
+    fn breadth_first(&self, seeds: &[Node]) -> Vec<Node>;
+    // The nodes in the order a (preorder) depth-first search visits them.
+    fn depth_first(&self, seeds: &[Node]) -> Vec<Node>;
+    // Every node that can be reached from the seeds, seeds included.
+    fn reachable(&self, seeds: &[Node]) -> HashSet<Node>;
+    // The number of edges on the shortest path from any of the seeds.
+    fn unit_distances(&self, seeds: &[Node]) -> HashMap<Node, usize>;
+}
+
+impl<'a, G, Distance> Unweighted<'a, G, Distance> {
+    pub fn new(graph: &'a G) -> Self {
+        Self {
+            graph,
+            distance: PhantomData,
+        }
+    }
+}
+
+impl<Node, Distance, G> UnweightedGraph<Node> for Unweighted<'_, G, Distance>
+where
+    G: Graph<Node, Distance>,
+{
+    fn successors(&self, node: &Node) -> Vec<Node> {
+        self.graph
+            .neighbours(node)
+            .into_iter()
+            .map(|(_, neighbour)| neighbour)
+            .collect()
+    }
+}
+
+impl<Node, T> Traversal<Node> for T
+where
+    T: UnweightedGraph<Node>,
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    fn breadth_first(&self, seeds: &[Node]) -> Vec<Node> {
+        let mut order = vec![];
+        visit_breadth_first(self, seeds, |node, _| order.push(node));
+        order
+    }
+
+    fn depth_first(&self, seeds: &[Node]) -> Vec<Node> {
+        let mut order = vec![];
+        let mut visited = HashSet::new();
+        // Seeds are pushed in reverse too, so that they are popped in order.
+        let mut stack: Vec<Node> = seeds.iter().rev().cloned().collect();
+
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            order.push(node);
+            stack.extend(
+                successors_to_pop(self, &node)
+                    .into_iter()
+                    .filter(|successor| !visited.contains(successor)),
+            );
+        }
+
+        order
+    }
+
+    fn reachable(&self, seeds: &[Node]) -> HashSet<Node> {
+        let mut visited: HashSet<Node> = seeds.iter().cloned().collect();
+        let mut stack: Vec<Node> = visited.iter().cloned().collect();
+
+        while let Some(node) = stack.pop() {
+            for successor in self.successors(&node) {
+                if visited.insert(successor) {
+                    stack.push(successor);
+                }
+            }
+        }
+
+        visited
+    }
+
+    fn unit_distances(&self, seeds: &[Node]) -> HashMap<Node, usize> {
+        let mut distances = HashMap::new();
+        visit_breadth_first(self, seeds, |node, distance| {
+            distances.insert(node, distance);
+        });
+        distances
+    }
+}
+
+// Reversed, so that popping them yields the successors in order.
+pub(super) fn successors_to_pop<Node>(
+    graph: &impl UnweightedGraph<Node>,
+    node: &Node,
+) -> Vec<Node> {
+    let mut successors = graph.successors(node);
+    successors.reverse();
+    successors
+}
+
+fn visit_breadth_first<Node>(
+    graph: &impl UnweightedGraph<Node>,
+    seeds: &[Node],
+    mut visit: impl FnMut(Node, usize),
+) where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for &seed in seeds {
+        if seen.insert(seed) {
+            queue.push_back((seed, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        visit(node, distance);
+        for successor in graph.successors(&node) {
+            if seen.insert(successor) {
+                queue.push_back((successor, distance + 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Disjoint sets of nodes, merged by union by size with path compression.
+// Nodes that were never mentioned are sets of their own.
+#[derive(Clone, Debug)]
+pub struct UnionFind<Node> {
+    parents: HashMap<Node, Node>,
+    // Only up to date for the representatives.
+    sizes: HashMap<Node, usize>,
+}
+
+impl<Node> UnionFind<Node>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    pub fn new() -> Self {
+        Self {
+            parents: HashMap::new(),
+            sizes: HashMap::new(),
+        }
+    }
+
+    pub fn from_nodes(nodes: impl IntoIterator<Item = Node>) -> Self {
+        let mut union_find = Self::new();
+        for node in nodes {
+            union_find.insert(node);
+        }
+        union_find
+    }
+
+    pub fn insert(&mut self, node: Node) {
+        if let Entry::Vacant(v) = self.parents.entry(node) {
+            v.insert(node);
+            self.sizes.insert(node, 1);
+        }
+    }
+
+    // The representative of the set the node belongs to.
+    pub fn find(&mut self, node: Node) -> Node {
+        self.insert(node);
+        let mut root = node;
+        while self.parents[&root] != root {
+            root = self.parents[&root];
+        }
+
+        let mut current = node;
+        while current != root {
+            current = self.parents.insert(current, root).unwrap();
+        }
+        root
+    }
+
+    // Merges the sets of both nodes, returning false if they already were the same.
+    pub fn union(&mut self, first: Node, second: Node) -> bool {
+        let (mut first, mut second) = (self.find(first), self.find(second));
+        if first == second {
+            return false;
+        }
+        if self.sizes[&first] < self.sizes[&second] {
+            (first, second) = (second, first);
+        }
+        self.parents.insert(second, first);
+        let size = self.sizes[&second];
+        *self.sizes.get_mut(&first).unwrap() += size;
+        true
+    }
+
+    pub fn connected(&mut self, first: Node, second: Node) -> bool {
+        self.find(first) == self.find(second)
+    }
+
+    pub fn set_size(&mut self, node: Node) -> usize {
+        let root = self.find(node);
+        self.sizes[&root]
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.parents
+            .iter()
+            .filter(|(node, parent)| node == parent)
+            .count()
+    }
+
+    pub fn sets(&mut self) -> Vec<Vec<Node>> {
+        let nodes: Vec<_> = self.parents.keys().cloned().collect();
+        let mut sets: HashMap<Node, Vec<Node>> = HashMap::new();
+        for node in nodes {
+            let root = self.find(node);
+            sets.entry(root).or_default().push(node);
+        }
+        sets.into_values().collect()
+    }
+}
+
+impl<Node> Default for UnionFind<Node>
+where
+    Node: Copy + Eq + Hash + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
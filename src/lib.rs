@@ -1,4 +1,6 @@
+pub mod bench;
 pub mod common;
+pub mod day1;
 pub mod day10;
 pub mod day11;
 pub mod day12;
@@ -12,7 +14,38 @@ pub mod day3;
 pub mod day4;
 pub mod day5;
 pub mod day6;
+pub mod day7;
 pub mod day8;
 pub mod day9;
 pub mod graph;
+pub mod number_theory;
+pub mod parse;
+pub mod solution;
 pub mod spatial;
+
+pub use solution::Day;
+pub use solution::Solution;
+
+pub const DAYS: [Day; 17] = [
+    Day::of::<day1::Day1>(1),
+    Day::of::<day2::Day2>(2),
+    Day::of::<day3::Day3>(3),
+    Day::of::<day4::Day4>(4),
+    Day::of::<day5::Day5>(5),
+    Day::of::<day6::Day6>(6),
+    Day::of::<day7::Day7>(7),
+    Day::of::<day8::Day8>(8),
+    Day::of::<day9::Day9>(9),
+    Day::of::<day10::Day10>(10),
+    Day::of::<day11::Day11>(11),
+    Day::of::<day12::Day12>(12),
+    Day::of::<day13::Day13>(13),
+    Day::of::<day14::Day14>(14),
+    Day::of::<day15::Day15>(15),
+    Day::of::<day16::Day16>(16),
+    Day::of::<day17::Day17>(17),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
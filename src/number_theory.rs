@@ -0,0 +1,106 @@
+use num::Integer;
+use num::Signed;
+use std::error::Error;
+use std::fmt;
+
+// x ≡ residue (mod modulus), with the residue kept within 0..modulus.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Congruence<T> {
+    pub residue: T,
+    pub modulus: T,
+}
+
+// Raised when no number satisfies all the congruences. The congruence at
+// `index` contradicts the ones before it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InconsistentCongruences {
+    pub index: usize,
+}
+
+// The gcd of a and b along with Bézout coefficients (x, y), such that
+// a * x + b * y == gcd. The gcd is never negative.
+pub fn extended_gcd<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// The x within 0..modulus such that a * x ≡ 1 (mod modulus), which only
+// exists when a and the modulus are coprime.
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, modulus: T) -> Option<T> {
+    let (gcd, x, _) = extended_gcd(a, modulus);
+    gcd.is_one().then(|| x.mod_floor(&modulus))
+}
+
+// Merges the congruences into a single one, modulo the lcm of all the moduli.
+// Unlike the textbook Chinese remainder theorem, the moduli need not be
+// coprime, as long as the residues agree wherever the moduli overlap.
+pub fn solve_congruences<T: Integer + Signed + Copy>(
+    congruences: &[Congruence<T>],
+) -> Result<Congruence<T>, InconsistentCongruences> {
+    congruences.iter().enumerate().try_fold(
+        Congruence::new(T::zero(), T::one()),
+        |merged, (index, next)| merged.merge(next).ok_or(InconsistentCongruences { index }),
+    )
+}
+
+impl<T: Integer + Signed + Copy> Congruence<T> {
+    pub fn new(residue: T, modulus: T) -> Self {
+        Self {
+            residue: residue.mod_floor(&modulus),
+            modulus,
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        x.mod_floor(&self.modulus) == self.residue
+    }
+
+    // The smallest number satisfying the congruence that is not below `lower`.
+    pub fn first_at_least(&self, lower: T) -> T {
+        lower + (self.residue - lower).mod_floor(&self.modulus)
+    }
+
+    // None if the two congruences have no solution in common.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        let (gcd, x, _) = extended_gcd(self.modulus, other.modulus);
+        let difference = other.residue - self.residue;
+        if !difference.is_multiple_of(&gcd) {
+            return None;
+        }
+
+        // self.modulus * x ≡ gcd (mod other.modulus), so stepping from self.residue
+        // by k * self.modulus reaches other.residue. Reducing both factors first
+        // keeps the product within (other.modulus / gcd)².
+        let step_modulus = other.modulus / gcd;
+        let k = ((difference / gcd).mod_floor(&step_modulus) * x.mod_floor(&step_modulus))
+            .mod_floor(&step_modulus);
+        let modulus = self.modulus * step_modulus;
+
+        Some(Self::new(self.residue + k * self.modulus, modulus))
+    }
+}
+
+impl fmt::Display for InconsistentCongruences {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "congruence {} contradicts the ones before it",
+            self.index
+        )
+    }
+}
+
+impl Error for InconsistentCongruences {}
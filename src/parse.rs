@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    UnexpectedToken(String),
+    InvalidNumber(String),
+    MissingSeparator(&'static str),
+    Expected(&'static str),
+}
+
+// Lines and columns are 1-indexed, as an editor would show them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl ParseError {
+    // For when the input ends before something we need shows up.
+    pub fn end_of_input(input: &str, kind: ParseErrorKind) -> Self {
+        Self {
+            line: input.lines().count() + 1,
+            column: 1,
+            kind,
+        }
+    }
+}
+
+impl<'a> Line<'a> {
+    pub fn error_at_offset(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            kind,
+        }
+    }
+
+    // The fragment must be a slice of this line's text, which is what every
+    // helper here hands out.
+    pub fn error_at(&self, fragment: &str, kind: ParseErrorKind) -> ParseError {
+        self.error_at_offset(self.offset_of(fragment), kind)
+    }
+
+    pub fn error_after(&self, fragment: &str, kind: ParseErrorKind) -> ParseError {
+        self.error_at_offset(self.offset_of(fragment) + fragment.len(), kind)
+    }
+
+    pub fn unexpected_character(&self, offset: usize, character: char) -> ParseError {
+        self.error_at_offset(offset, ParseErrorKind::UnexpectedCharacter(character))
+    }
+
+    pub fn split_once(
+        &self,
+        fragment: &'a str,
+        separator: &'static str,
+    ) -> ParseResult<(&'a str, &'a str)> {
+        fragment
+            .split_once(separator)
+            .ok_or_else(|| self.error_after(fragment, ParseErrorKind::MissingSeparator(separator)))
+    }
+
+    pub fn number<T: FromStr>(&self, fragment: &'a str) -> ParseResult<T> {
+        fragment.parse().map_err(|_| {
+            self.error_at(
+                fragment,
+                ParseErrorKind::InvalidNumber(fragment.to_string()),
+            )
+        })
+    }
+
+    pub fn numbers<T: FromStr>(&self, fragment: &'a str) -> ParseResult<Vec<T>> {
+        fragment
+            .split_ascii_whitespace()
+            .map(|raw| self.number(raw))
+            .collect()
+    }
+
+    // Like str::char_indices, but the offsets are relative to the whole line.
+    pub fn char_indices(&self, fragment: &'a str) -> impl Iterator<Item = (usize, char)> + 'a {
+        let start = self.offset_of(fragment);
+        fragment
+            .char_indices()
+            .map(move |(idx, character)| (start + idx, character))
+    }
+
+    fn offset_of(&self, fragment: &str) -> usize {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        debug_assert!(offset <= self.text.len(), "fragment outside of line");
+        offset.min(self.text.len())
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Line {
+        number: idx + 1,
+        text,
+    })
+}
+
+// Groups the lines of the input into blocks separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut current = vec![];
+
+    for line in lines(input) {
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(current);
+                current = vec![];
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+// Checks that the lines form a rectangular grid and returns its width.
+pub fn grid_width(lines: &[Line]) -> ParseResult<usize> {
+    let width = lines.first().map_or(0, |line| line.text.chars().count());
+
+    match lines.iter().find(|line| line.text.chars().count() != width) {
+        Some(line) => {
+            let offset = line
+                .text
+                .char_indices()
+                .nth(width)
+                .map_or(line.text.len(), |(offset, _)| offset);
+            Err(line.error_at_offset(
+                offset,
+                ParseErrorKind::Expected("all rows to have the same length"),
+            ))
+        }
+        None => Ok(width),
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedCharacter(character) => {
+                write!(f, "unexpected character {:?}", character)
+            }
+            ParseErrorKind::UnexpectedToken(token) => write!(f, "unexpected token {:?}", token),
+            ParseErrorKind::InvalidNumber(raw) => write!(f, "invalid number {:?}", raw),
+            ParseErrorKind::MissingSeparator(separator) => {
+                write!(f, "missing separator {:?}", separator)
+            }
+            ParseErrorKind::Expected(expected) => write!(f, "expected {}", expected),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseError {}
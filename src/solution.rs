@@ -0,0 +1,61 @@
+use anyhow::Result;
+use std::fmt::Display;
+
+pub trait Solution {
+    type Puzzle;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Puzzle>;
+    fn part_one(puzzle: &Self::Puzzle) -> Result<Self::Answer>;
+    fn part_two(puzzle: &Self::Puzzle) -> Result<Self::Answer>;
+}
+
+// The type-erased counterpart of a parsed Solution::Puzzle, so days with different
+// puzzle and answer types can be stored and driven side by side.
+pub trait Puzzle {
+    fn part_one(&self) -> Result<String>;
+    fn part_two(&self) -> Result<String>;
+}
+
+struct ParsedPuzzle<S: Solution>(S::Puzzle);
+
+impl<S: Solution> Puzzle for ParsedPuzzle<S> {
+    fn part_one(&self) -> Result<String> {
+        S::part_one(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part_two(&self) -> Result<String> {
+        S::part_two(&self.0).map(|answer| answer.to_string())
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<Box<dyn Puzzle>>,
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>(number: u8) -> Self {
+        Self {
+            number,
+            parse: parse_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Puzzle>> {
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, input: &str) -> Result<(String, String)> {
+        let puzzle = self.parse(input)?;
+        Ok((puzzle.part_one()?, puzzle.part_two()?))
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("data/day{}/input", self.number)
+    }
+}
+
+fn parse_erased<S: Solution + 'static>(input: &str) -> Result<Box<dyn Puzzle>> {
+    Ok(Box::new(ParsedPuzzle::<S>(S::parse(input)?)))
+}
@@ -1,3 +1,8 @@
+use num::Signed;
+use std::error::Error;
+use std::fmt;
+use std::ops::Add;
+use std::ops::Mul;
 use std::ops::Neg;
 use std::ops::Sub;
 
@@ -7,6 +12,20 @@ pub struct UPoint2D {
     pub column: usize,
 }
 
+// Unlike UPoint2D, coordinates are not padded and may be negative.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2D<T> {
+    pub row: T,
+    pub column: T,
+}
+
+// The offset between two points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector2D<T> {
+    pub row: T,
+    pub column: T,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Direction {
     Up,
@@ -15,11 +34,40 @@ pub enum Direction {
     Right,
 }
 
+// The eight directions, listed clockwise starting from North (which is Up).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+// Both corners are inclusive.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rectangle {
+    pub top_left: UPoint2D,
+    pub bottom_right: UPoint2D,
+}
+
 pub enum Orientation {
     Clockwise,
     Counterclockwise,
 }
 
+// Raised when converting between UPoint2D and Point2D.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PointConversionError {
+    // Row or column 0 of a UPoint2D is padding, with no unpadded counterpart.
+    Padding,
+    // A coordinate does not fit in the target type.
+    OutOfRange,
+}
+
 impl UPoint2D {
     pub fn from(row: usize, column: usize) -> Self {
         // We pad all coordinates by 1 not to deal with overflows in unsigned integers.
@@ -40,6 +88,23 @@ impl UPoint2D {
         .collect()
     }
 
+    pub fn neighbours8(&self) -> Vec<UPoint2D> {
+        Compass::ALL
+            .into_iter()
+            .filter_map(|compass| self.move_towards(compass))
+            .collect()
+    }
+
+    pub fn move_towards(&self, compass: Compass) -> Option<UPoint2D> {
+        let offset = Vector2D::<isize>::from(compass);
+        let destination = UPoint2D {
+            row: self.row.checked_add_signed(offset.row)?,
+            column: self.column.checked_add_signed(offset.column)?,
+        };
+
+        Some(destination).filter(Self::is_valid)
+    }
+
     pub fn move_by(&self, direction: Direction) -> Option<UPoint2D> {
         let destination = match direction {
             Direction::Up => UPoint2D {
@@ -72,6 +137,151 @@ impl UPoint2D {
     }
 }
 
+impl<T: Signed + Copy + Ord> Point2D<T> {
+    pub fn new(row: T, column: T) -> Self {
+        Self { row, column }
+    }
+
+    pub fn move_by(self, direction: Direction) -> Self {
+        self + Vector2D::from(direction)
+    }
+
+    pub fn neighbours(self) -> Vec<Point2D<T>> {
+        vec![
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+        .into_iter()
+        .map(|direction| self.move_by(direction))
+        .collect()
+    }
+
+    pub fn neighbours8(self) -> Vec<Point2D<T>> {
+        Compass::ALL
+            .into_iter()
+            .map(|compass| self + Vector2D::from(compass))
+            .collect()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self - other).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self - other).chebyshev_length()
+    }
+}
+
+impl<T: Signed + Copy + Ord> Vector2D<T> {
+    pub fn new(row: T, column: T) -> Self {
+        Self { row, column }
+    }
+
+    pub fn manhattan_length(self) -> T {
+        self.row.abs() + self.column.abs()
+    }
+
+    pub fn chebyshev_length(self) -> T {
+        self.row.abs().max(self.column.abs())
+    }
+}
+
+impl Compass {
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    pub fn rotate45(self, orientation: Orientation) -> Self {
+        self.rotate_by(orientation, 1)
+    }
+
+    pub fn rotate90(self, orientation: Orientation) -> Self {
+        self.rotate_by(orientation, 2)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    fn rotate_by(self, orientation: Orientation, eighths: usize) -> Self {
+        let steps = match orientation {
+            Orientation::Clockwise => eighths,
+            Orientation::Counterclockwise => Self::ALL.len() - eighths,
+        };
+        Self::ALL[(self as usize + steps) % Self::ALL.len()]
+    }
+}
+
+impl Rectangle {
+    pub fn new(top_left: UPoint2D, bottom_right: UPoint2D) -> Self {
+        Self {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    // Grows the rectangle by margin on every side, stopping at the padding row and column.
+    pub fn grow(&self, margin: usize) -> Self {
+        Self {
+            top_left: UPoint2D {
+                row: self.top_left.row.saturating_sub(margin),
+                column: self.top_left.column.saturating_sub(margin),
+            },
+            bottom_right: UPoint2D {
+                row: self.bottom_right.row + margin,
+                column: self.bottom_right.column + margin,
+            },
+        }
+    }
+
+    pub fn contains(&self, point: UPoint2D) -> bool {
+        (self.top_left.row..=self.bottom_right.row).contains(&point.row)
+            && (self.top_left.column..=self.bottom_right.column).contains(&point.column)
+    }
+
+    // Walks the border clockwise from the top left corner, visiting every cell once
+    // and skipping the ones that lie in the padding.
+    pub fn perimeter(&self) -> impl Iterator<Item = UPoint2D> {
+        let UPoint2D {
+            row: top,
+            column: left,
+        } = self.top_left;
+        let UPoint2D {
+            row: bottom,
+            column: right,
+        } = self.bottom_right;
+
+        let top_side = (left..=right).map(move |column| UPoint2D { row: top, column });
+        let right_side = (top + 1..=bottom).map(move |row| UPoint2D { row, column: right });
+        let bottom_side = (left..right)
+            .rev()
+            .filter(move |_| bottom > top)
+            .map(move |column| UPoint2D {
+                row: bottom,
+                column,
+            });
+        let left_side = (top + 1..bottom)
+            .rev()
+            .filter(move |_| right > left)
+            .map(move |row| UPoint2D { row, column: left });
+
+        top_side
+            .chain(right_side)
+            .chain(bottom_side)
+            .chain(left_side)
+            .filter(UPoint2D::is_valid)
+    }
+}
+
 impl Direction {
     pub fn rotate(self, orientation: Orientation) -> Self {
         match orientation {
@@ -102,6 +312,181 @@ impl Sub for UPoint2D {
     }
 }
 
+impl<T: Add<Output = T>> Add<Vector2D<T>> for Point2D<T> {
+    type Output = Self;
+
+    fn add(self, offset: Vector2D<T>) -> Self::Output {
+        Self {
+            row: self.row + offset.row,
+            column: self.column + offset.column,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector2D<T>> for Point2D<T> {
+    type Output = Self;
+
+    fn sub(self, offset: Vector2D<T>) -> Self::Output {
+        Self {
+            row: self.row - offset.row,
+            column: self.column - offset.column,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2D<T> {
+    type Output = Vector2D<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector2D {
+            row: self.row - other.row,
+            column: self.column - other.column,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2D<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Self {
+            row: self.row * factor,
+            column: self.column * factor,
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector2D<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            row: self.row + other.row,
+            column: self.column + other.column,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector2D<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            row: self.row - other.row,
+            column: self.column - other.column,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector2D<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Self {
+            row: self.row * factor,
+            column: self.column * factor,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            row: -self.row,
+            column: -self.column,
+        }
+    }
+}
+
+impl<T: Signed> From<Direction> for Vector2D<T> {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self {
+                row: -T::one(),
+                column: T::zero(),
+            },
+            Direction::Down => Self {
+                row: T::one(),
+                column: T::zero(),
+            },
+            Direction::Left => Self {
+                row: T::zero(),
+                column: -T::one(),
+            },
+            Direction::Right => Self {
+                row: T::zero(),
+                column: T::one(),
+            },
+        }
+    }
+}
+
+impl<T: Signed> From<Compass> for Vector2D<T> {
+    fn from(compass: Compass) -> Self {
+        let (row, column) = match compass {
+            Compass::North => (-T::one(), T::zero()),
+            Compass::NorthEast => (-T::one(), T::one()),
+            Compass::East => (T::zero(), T::one()),
+            Compass::SouthEast => (T::one(), T::one()),
+            Compass::South => (T::one(), T::zero()),
+            Compass::SouthWest => (T::one(), -T::one()),
+            Compass::West => (T::zero(), -T::one()),
+            Compass::NorthWest => (-T::one(), -T::one()),
+        };
+        Self { row, column }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+            Direction::Right => Compass::East,
+        }
+    }
+}
+
+// Conversions strip (and restore) the padding of UPoint2D, so that
+// UPoint2D::from(row, column) maps to Point2D { row, column }.
+impl<T: TryFrom<usize>> TryFrom<UPoint2D> for Point2D<T> {
+    type Error = PointConversionError;
+
+    fn try_from(point: UPoint2D) -> Result<Self, Self::Error> {
+        let unpad = |coordinate: usize| {
+            let coordinate = coordinate
+                .checked_sub(1)
+                .ok_or(PointConversionError::Padding)?;
+            T::try_from(coordinate).map_err(|_| PointConversionError::OutOfRange)
+        };
+        Ok(Self {
+            row: unpad(point.row)?,
+            column: unpad(point.column)?,
+        })
+    }
+}
+
+impl<T: TryInto<usize>> TryFrom<Point2D<T>> for UPoint2D {
+    type Error = PointConversionError;
+
+    fn try_from(point: Point2D<T>) -> Result<Self, Self::Error> {
+        let pad = |coordinate: T| {
+            coordinate
+                .try_into()
+                .ok()
+                .and_then(|coordinate: usize| coordinate.checked_add(1))
+                .ok_or(PointConversionError::OutOfRange)
+        };
+        Ok(Self {
+            row: pad(point.row)?,
+            column: pad(point.column)?,
+        })
+    }
+}
+
 impl Neg for Direction {
     type Output = Self;
 
@@ -114,3 +499,16 @@ impl Neg for Direction {
         }
     }
 }
+
+impl fmt::Display for PointConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PointConversionError::Padding => write!(f, "the point lies on the padding"),
+            PointConversionError::OutOfRange => {
+                write!(f, "a coordinate is out of range for the point type")
+            }
+        }
+    }
+}
+
+impl Error for PointConversionError {}
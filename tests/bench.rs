@@ -0,0 +1,39 @@
+use aoc_2023::bench::Baseline;
+use aoc_2023::bench::Change;
+use aoc_2023::bench::Measurement;
+use aoc_2023::bench::Phase;
+use aoc_2023::bench::Summary;
+use std::time::Duration;
+
+fn measurement(mean: Duration) -> Measurement {
+    Measurement {
+        day: 1,
+        phase: Phase::PartOne,
+        summary: Summary::from_samples(&[mean]),
+    }
+}
+
+#[test]
+fn change_against_the_baseline() {
+    let baseline = Baseline::from([((1, Phase::PartOne), Duration::from_millis(10))]);
+
+    let slower = measurement(Duration::from_millis(15)).change(&baseline);
+    let faster = measurement(Duration::from_millis(9)).change(&baseline);
+
+    assert_eq!(slower.unwrap().to_string(), "+50.0%");
+    assert!(slower.is_some_and(|change| change.is_regression()));
+    assert!(faster.is_some_and(|change| !change.is_regression()));
+    assert_eq!(faster.unwrap().to_string(), "-10.0%");
+    assert_eq!(measurement(Duration::ZERO).change(&Baseline::new()), None);
+}
+
+#[test]
+fn change_against_a_zero_baseline_is_not_applicable() {
+    let baseline = Baseline::from([((1, Phase::PartOne), Duration::ZERO)]);
+
+    let change = measurement(Duration::from_nanos(5)).change(&baseline);
+
+    assert_eq!(change, Some(Change::NotApplicable));
+    assert_eq!(change.unwrap().to_string(), "n/a");
+    assert!(!change.unwrap().is_regression());
+}
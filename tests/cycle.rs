@@ -0,0 +1,99 @@
+use aoc_2023::common::nth_with_cycle_skip;
+use aoc_2023::common::Brent;
+use aoc_2023::common::Cycle;
+use aoc_2023::common::CycleDetection;
+use aoc_2023::common::Floyd;
+use aoc_2023::common::SeenStates;
+use std::cell::Cell;
+use std::iter::successors;
+
+// The first state to repeat, found by brute force.
+fn first_repeat(states: &[u32]) -> Cycle<u32> {
+    let index = (1..states.len())
+        .find(|&index| states[..index].contains(&states[index]))
+        .unwrap();
+    let mu = states
+        .iter()
+        .position(|&state| state == states[index])
+        .unwrap();
+    Cycle {
+        mu,
+        lambda: index - mu,
+        states: states[mu..index].to_vec(),
+    }
+}
+
+#[test]
+fn detectors_agree_with_brute_force() {
+    for seed in 0..50 {
+        let sequence = successors(Some(seed), |&x| Some((x * x + 1) % 255));
+        let expected = first_repeat(&sequence.clone().take(300).collect::<Vec<_>>());
+
+        assert_eq!(sequence.detect_cycle_with(Floyd).as_ref(), Some(&expected));
+        assert_eq!(sequence.detect_cycle_with(Brent).as_ref(), Some(&expected));
+        assert_eq!(
+            sequence.detect_cycle_with(SeenStates).as_ref(),
+            Some(&expected)
+        );
+        assert_eq!(sequence.detect_cycle(), Some(expected));
+    }
+}
+
+#[test]
+fn fixed_points_are_cycles_of_length_one() {
+    let sequence = [5, 3, 3, 3, 3, 3, 3].into_iter();
+
+    let cycle = sequence.detect_cycle().unwrap();
+
+    assert_eq!((cycle.mu, cycle.lambda, cycle.states), (1, 1, vec![3]));
+}
+
+#[test]
+fn finite_sequences_without_repeats_have_no_cycle() {
+    let sequence = 0..100;
+
+    assert_eq!(sequence.detect_cycle_with(Floyd), None);
+    assert_eq!(sequence.detect_cycle_with(Brent), None);
+    assert_eq!(sequence.detect_cycle_with(SeenStates), None);
+    assert_eq!((0..0).detect_cycle(), None);
+}
+
+#[test]
+fn nth_with_cycle_skip_matches_walking_the_sequence() {
+    let sequence = successors(Some(7), |&x| Some((x * x + 1) % 255));
+    let cycle = sequence.detect_cycle().unwrap();
+
+    for n in 0..100 {
+        assert_eq!(
+            nth_with_cycle_skip(sequence.clone(), n),
+            sequence.clone().nth(n as usize)
+        );
+    }
+    assert_eq!(
+        nth_with_cycle_skip(sequence, u64::MAX).as_ref(),
+        cycle.state_at(u64::MAX)
+    );
+    assert_eq!(cycle.state_at(cycle.mu as u64 - 1), None);
+}
+
+#[test]
+fn nth_with_cycle_skip_computes_every_state_once() {
+    let sequence = successors(Some(7), |&x| Some((x * x + 1) % 255));
+    let cycle = sequence.clone().detect_cycle().unwrap();
+    let states = Cell::new(0);
+
+    nth_with_cycle_skip(
+        sequence.inspect(|_| states.set(states.get() + 1)),
+        1_000_000_000_000,
+    );
+
+    // Up to and including the first repeated state.
+    assert_eq!(states.get(), cycle.mu + cycle.lambda + 1);
+}
+
+#[test]
+fn nth_with_cycle_skip_on_finite_sequences() {
+    assert_eq!(nth_with_cycle_skip(0..10, 3), Some(3));
+    assert_eq!(nth_with_cycle_skip(0..10, 10), None);
+    assert_eq!(nth_with_cycle_skip([1, 2, 1], 1_001), Some(2));
+}
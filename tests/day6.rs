@@ -0,0 +1,59 @@
+use aoc_2023::day6::beat_record;
+use aoc_2023::day6::Distance;
+use aoc_2023::day6::Time;
+use proptest::prelude::*;
+
+fn brute_force(total_time: Time, target_distance: Distance) -> u64 {
+    (0..=total_time)
+        .filter(|hold| hold * (total_time - hold) > target_distance)
+        .count() as u64
+}
+
+// A race along with a record up to past the farthest distance it allows, (T / 2)².
+fn small_race() -> impl Strategy<Value = (Time, Distance)> {
+    (0..2_000 as Time).prop_flat_map(|total_time| {
+        (
+            Just(total_time),
+            0..=total_time * total_time / 4 + 2 as Distance,
+        )
+    })
+}
+
+// A race along with a hold time shorter than half of it, small enough for the
+// distances to fit.
+fn large_race() -> impl Strategy<Value = (Time, Time)> {
+    (3..8_000_000_000 as Time)
+        .prop_flat_map(|total_time| (Just(total_time), 1..=(total_time - 1) / 2))
+}
+
+proptest! {
+    #[test]
+    fn beat_record_matches_brute_force((total_time, target_distance) in small_race()) {
+        prop_assert_eq!(
+            beat_record(total_time, target_distance),
+            brute_force(total_time, target_distance)
+        );
+    }
+
+    // Holding for exactly `shortest` ties the record, while holding any longer,
+    // up to T - shortest exclusive, beats it.
+    #[test]
+    fn beat_record_is_exact_for_large_races((total_time, shortest) in large_race()) {
+        let record_at_shortest = shortest * (total_time - shortest);
+
+        prop_assert_eq!(
+            beat_record(total_time, record_at_shortest - 1),
+            total_time - 2 * shortest + 1
+        );
+        prop_assert_eq!(
+            beat_record(total_time, record_at_shortest),
+            total_time - 2 * shortest - 1
+        );
+    }
+}
+
+#[test]
+fn beat_record_at_the_limits() {
+    assert_eq!(beat_record(0, 0), 0);
+    assert_eq!(beat_record(Time::MAX, Distance::MAX), Time::MAX - 3);
+}
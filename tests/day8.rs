@@ -0,0 +1,74 @@
+use aoc_2023::day8::parse_input;
+use aoc_2023::day8::TerminalHits;
+use std::collections::HashMap;
+
+// The terminal hits of the ghosts starting at 11A and 22A, in that order.
+fn ghost_hits(input: &str) -> (TerminalHits, TerminalHits, HashMap<&str, usize>) {
+    let (directions, network) = parse_input(input).unwrap();
+    let hits = |name| {
+        network.terminal_hits(network.id(name).unwrap(), &directions, |name| {
+            name.ends_with('Z')
+        })
+    };
+    let ids = ["11Z", "22Z"]
+        .into_iter()
+        .map(|name| (name, network.id(name).unwrap()))
+        .collect();
+
+    (hits("11A"), hits("22A"), ids)
+}
+
+#[test]
+fn terminal_hits_of_ghosts_that_loop_right_away() {
+    let (first, second, ids) = ghost_hits(include_str!("../data/day8/test_input_4"));
+
+    assert_eq!(
+        first,
+        TerminalHits {
+            before_cycle: vec![],
+            cycle_start: 0,
+            cycle_length: 3,
+            in_cycle: HashMap::from([((ids["11Z"], 0), 0)]),
+        }
+    );
+    assert_eq!(
+        second,
+        TerminalHits {
+            before_cycle: vec![],
+            cycle_start: 0,
+            cycle_length: 2,
+            in_cycle: HashMap::from([((ids["22Z"], 0), 1)]),
+        }
+    );
+    assert!([1, 4, 7].iter().all(|&time| first.hits_at(time)));
+    assert!([2, 4, 6].iter().all(|&time| second.hits_at(time)));
+    assert!(!first.hits_at(2) && !second.hits_at(3));
+}
+
+#[test]
+fn terminal_hits_before_the_cycle() {
+    let (first, second, ids) = ghost_hits(include_str!("../data/day8/test_input_5"));
+
+    // 11A only passes 11Z once, on the way into the 11B-11C loop.
+    assert_eq!(
+        first,
+        TerminalHits {
+            before_cycle: vec![1],
+            cycle_start: 1,
+            cycle_length: 2,
+            in_cycle: HashMap::new(),
+        }
+    );
+    assert_eq!(
+        second,
+        TerminalHits {
+            before_cycle: vec![],
+            cycle_start: 0,
+            cycle_length: 1,
+            in_cycle: HashMap::from([((ids["22Z"], 0), 0)]),
+        }
+    );
+    assert!(first.hits_at(1));
+    assert!((2..10).all(|time| !first.hits_at(time)));
+    assert!(first.congruences().is_empty());
+}
@@ -0,0 +1,118 @@
+use aoc_2023::Day;
+use aoc_2023::DAYS;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+// Every data/dayN/test_input* file must have a table of the same name in
+// data/dayN/expected.toml listing the answers for the parts it applies to, e.g.
+//
+// [test_input]
+// part_one = 142
+// part_two = 281
+//
+// Samples that only make sense for one of the parts simply omit the other one.
+#[test]
+fn sample_answers_match_expected() {
+    let failures: Vec<String> = DAYS.iter().flat_map(check_day).collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} sample answer(s) did not match:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+fn check_day(day: &Day) -> Vec<String> {
+    let directory = data_directory(day);
+    let expected = match read_expected(&directory) {
+        Ok(expected) => expected,
+        Err(error) => return vec![format!("day {}: {}", day.number, error)],
+    };
+    let mut failures = vec![];
+
+    for sample in discover_samples(&directory) {
+        match expected.get(&sample).and_then(toml::Value::as_table) {
+            Some(answers) => failures.extend(check_sample(day, &directory, &sample, answers)),
+            None => failures.push(format!(
+                "day {}: {} has no expected answers",
+                day.number, sample
+            )),
+        }
+    }
+    for sample in expected.keys() {
+        if !directory.join(sample).is_file() {
+            failures.push(format!(
+                "day {}: expected answers for missing sample {}",
+                day.number, sample
+            ));
+        }
+    }
+
+    failures
+}
+
+fn check_sample(day: &Day, directory: &Path, sample: &str, answers: &toml::Table) -> Vec<String> {
+    let context = format!("day {} {}", day.number, sample);
+    let puzzle = match fs::read_to_string(directory.join(sample))
+        .map_err(anyhow::Error::from)
+        .and_then(|input| day.parse(&input))
+    {
+        Ok(puzzle) => puzzle,
+        Err(error) => return vec![format!("{}: {}", context, error)],
+    };
+    let mut failures = vec![];
+
+    for (part, answer) in answers {
+        let actual = match part.as_str() {
+            "part_one" => puzzle.part_one(),
+            "part_two" => puzzle.part_two(),
+            _ => {
+                failures.push(format!("{}: unknown part {}", context, part));
+                continue;
+            }
+        };
+        let expected = match answer {
+            toml::Value::String(answer) => answer.clone(),
+            answer => answer.to_string(),
+        };
+
+        match actual {
+            Ok(actual) if actual == expected => (),
+            Ok(actual) => failures.push(format!(
+                "{} {}: expected {}, got {}",
+                context, part, expected, actual
+            )),
+            Err(error) => failures.push(format!("{} {}: {}", context, part, error)),
+        }
+    }
+
+    failures
+}
+
+fn data_directory(day: &Day) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(format!("day{}", day.number))
+}
+
+fn read_expected(directory: &Path) -> Result<toml::Table, String> {
+    let path = directory.join("expected.toml");
+    fs::read_to_string(&path)
+        .map_err(|error| format!("could not read {}: {}", path.display(), error))?
+        .parse()
+        .map_err(|error| format!("could not parse {}: {}", path.display(), error))
+}
+
+fn discover_samples(directory: &Path) -> Vec<String> {
+    let mut samples: Vec<String> = fs::read_dir(directory)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with("test_input"))
+        .collect();
+    samples.sort();
+    samples
+}
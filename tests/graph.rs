@@ -0,0 +1,784 @@
+use aoc_2023::common::Grid;
+use aoc_2023::day17;
+use aoc_2023::day17::BaseCityMap;
+use aoc_2023::day17::CruciblePathStep;
+use aoc_2023::day17::RegularCityMap;
+use aoc_2023::day17::UltracrucibleCityMap;
+use aoc_2023::graph::AStar;
+use aoc_2023::graph::AdjacencyList;
+use aoc_2023::graph::AdjacencyMatrix;
+use aoc_2023::graph::AllPairs;
+use aoc_2023::graph::BellmanFord;
+use aoc_2023::graph::Compress;
+use aoc_2023::graph::Connectivity;
+use aoc_2023::graph::Dag;
+use aoc_2023::graph::Dial;
+use aoc_2023::graph::Dijkstra;
+use aoc_2023::graph::EdgeMode;
+use aoc_2023::graph::FlowNetwork;
+use aoc_2023::graph::Graph;
+use aoc_2023::graph::IndexedGraph;
+use aoc_2023::graph::LongestSimplePath;
+use aoc_2023::graph::SearchEvent;
+use aoc_2023::graph::SearchStats;
+use aoc_2023::graph::Traversal;
+use aoc_2023::graph::UnionFind;
+use aoc_2023::graph::Unweighted;
+use aoc_2023::spatial::UPoint2D;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// A small directed graph given by its weighted edges.
+struct Edges<Distance>(HashMap<char, Vec<(Distance, char)>>);
+
+impl<Distance: Copy> Edges<Distance> {
+    fn from(edges: &[(char, char, Distance)]) -> Self {
+        let mut adjacency: HashMap<char, Vec<(Distance, char)>> = HashMap::new();
+        for &(from, to, distance) in edges {
+            adjacency.entry(from).or_default().push((distance, to));
+        }
+        Self(adjacency)
+    }
+}
+
+impl<Distance: Copy> Graph<char, Distance> for Edges<Distance> {
+    fn neighbours(&self, node: &char) -> Vec<(Distance, char)> {
+        self.0.get(node).cloned().unwrap_or_default()
+    }
+}
+
+// The open cells of a grid, connected to their 4 neighbours.
+struct Maze(Grid<char>);
+
+impl Graph<UPoint2D, u32> for Maze {
+    fn neighbours(&self, node: &UPoint2D) -> Vec<(u32, UPoint2D)> {
+        self.0
+            .neighbours(*node)
+            .into_iter()
+            .filter(|&neighbour| *self.0.at(neighbour) == '.')
+            .map(|neighbour| (1, neighbour))
+            .collect()
+    }
+}
+
+// Two routes of length 3 from a to d, and a longer direct edge.
+fn diamond() -> Edges<u32> {
+    Edges::from(&[
+        ('a', 'b', 1),
+        ('a', 'c', 2),
+        ('b', 'd', 2),
+        ('c', 'd', 1),
+        ('a', 'd', 5),
+    ])
+}
+
+#[test]
+fn shortest_path_goes_from_seed_to_goal() {
+    let path = diamond()
+        .shortest_path(&['a'], |&node| node == 'd')
+        .unwrap();
+
+    assert_eq!(path.distance, 3);
+    assert_eq!(path.nodes.first(), Some(&'a'));
+    assert_eq!(path.nodes.last(), Some(&'d'));
+    assert_eq!(path.nodes.len(), 3);
+}
+
+#[test]
+fn shortest_path_of_a_seed_that_is_a_goal() {
+    let path = diamond()
+        .shortest_path(&['a'], |&node| node == 'a')
+        .unwrap();
+
+    assert_eq!(path.distance, 0);
+    assert_eq!(path.nodes, ['a']);
+}
+
+#[test]
+fn all_shortest_paths_finds_every_tie() {
+    let mut paths: Vec<_> = diamond()
+        .all_shortest_paths(&['a'], |&node| node == 'd')
+        .into_iter()
+        .map(|path| (path.distance, path.nodes.into_iter().collect::<String>()))
+        .collect();
+    paths.sort();
+
+    assert_eq!(paths, [(3, "abd".to_string()), (3, "acd".to_string())]);
+}
+
+#[test]
+fn unreachable_goals_have_no_path() {
+    let graph = diamond();
+
+    assert_eq!(graph.shortest_path(&['d'], |&node| node == 'a'), None);
+    assert!(graph
+        .all_shortest_paths(&['d'], |&node| node == 'a')
+        .is_empty());
+}
+
+// The day 17 sample, where the best crucible route loses 102 heat.
+fn sample_city() -> BaseCityMap {
+    BaseCityMap::parse(include_str!("../data/day17/test_input")).unwrap()
+}
+
+#[test]
+fn crucible_route_adds_up_to_its_heat_loss() {
+    let base = sample_city();
+    let goal = base.goal();
+
+    let route = RegularCityMap(&base)
+        .shortest_path(&CruciblePathStep::seeds(), |step| step.position == goal)
+        .unwrap();
+    let heat_loss: day17::HeatLoss = route.nodes[1..]
+        .iter()
+        .map(|step| *base.0.at(step.position))
+        .sum();
+
+    assert_eq!(route.distance, 102);
+    assert_eq!(heat_loss, route.distance);
+    assert_eq!(
+        base.render_route(&route)
+            .matches(['>', '<', '^', 'v'])
+            .count(),
+        route.nodes.len() - 1
+    );
+}
+
+#[test]
+fn a_star_agrees_with_dijkstra_and_expands_less() {
+    let base = sample_city();
+    let goal = base.goal();
+    let map = RegularCityMap(&base);
+    let is_goal = |step: &CruciblePathStep| step.position == goal;
+
+    let guided = map.a_star(&CruciblePathStep::seeds(), is_goal, base.heuristic());
+    let mut settled = 0;
+    let (distance, stats) = map.traced_distance(&CruciblePathStep::seeds(), is_goal, |event| {
+        if let SearchEvent::Settle { .. } = event {
+            settled += 1;
+        }
+    });
+
+    assert_eq!(distance, Some(102));
+    assert_eq!(guided.path.map(|path| path.distance), distance);
+    // Dijkstra settles the goal too, but stops before expanding it.
+    assert_eq!(settled, stats.popped - stats.stale);
+    assert!(guided.expanded < settled - 1);
+}
+
+#[test]
+fn guided_dial_agrees_with_dijkstra_on_crucibles() {
+    let base = sample_city();
+    let goal = base.goal();
+    let ultra = UltracrucibleCityMap(&base);
+    let is_goal = |step: &CruciblePathStep| step.position == goal && step.consecutive >= 4;
+
+    assert_eq!(
+        ultra.dial_distance_with(&CruciblePathStep::seeds(), is_goal, base.heuristic()),
+        ultra.distance(&CruciblePathStep::seeds(), is_goal)
+    );
+    assert_eq!(
+        RegularCityMap(&base).dial_distance_with(
+            &CruciblePathStep::seeds(),
+            |step| step.position == goal,
+            base.heuristic()
+        ),
+        Some(102)
+    );
+}
+
+#[test]
+fn traced_distance_reports_every_settle_and_relax() {
+    let mut events = vec![];
+
+    let (distance, stats) =
+        diamond().traced_distance(&['a'], |&node| node == 'd', |event| events.push(event));
+
+    assert_eq!(distance, Some(3));
+    assert_eq!(
+        stats,
+        SearchStats {
+            popped: 4,
+            stale: 0,
+            max_heap_size: 3
+        }
+    );
+    assert_eq!(
+        events,
+        [
+            SearchEvent::Settle {
+                node: 'a',
+                distance: 0
+            },
+            SearchEvent::Relax {
+                from: 'a',
+                to: 'b',
+                distance: 1
+            },
+            SearchEvent::Relax {
+                from: 'a',
+                to: 'c',
+                distance: 2
+            },
+            SearchEvent::Relax {
+                from: 'a',
+                to: 'd',
+                distance: 5
+            },
+            SearchEvent::Settle {
+                node: 'b',
+                distance: 1
+            },
+            SearchEvent::Relax {
+                from: 'b',
+                to: 'd',
+                distance: 3
+            },
+            SearchEvent::Settle {
+                node: 'c',
+                distance: 2
+            },
+            SearchEvent::Settle {
+                node: 'd',
+                distance: 3
+            },
+        ]
+    );
+}
+
+#[test]
+fn traced_distance_counts_stale_entries() {
+    let mut settled = 0;
+
+    // Without a goal the search runs dry, popping the entry d got before its
+    // distance improved from 5 to 3.
+    let (distance, stats) = diamond().traced_distance(
+        &['a'],
+        |_| false,
+        |event| {
+            if let SearchEvent::Settle { .. } = event {
+                settled += 1;
+            }
+        },
+    );
+
+    assert_eq!(distance, None);
+    assert_eq!(
+        stats,
+        SearchStats {
+            popped: 5,
+            stale: 1,
+            max_heap_size: 3
+        }
+    );
+    assert_eq!(settled, 4);
+}
+
+#[test]
+fn breadth_first_visits_by_increasing_edge_count() {
+    let graph = diamond();
+    let unweighted = Unweighted::new(&graph);
+
+    let order = unweighted.breadth_first(&['a']);
+    let distances = unweighted.unit_distances(&['a']);
+
+    assert_eq!(order[0], 'a');
+    assert_eq!(order[3], 'd');
+    assert_eq!(distances[&'d'], 1);
+    assert_eq!(distances[&'c'], 1);
+}
+
+#[test]
+fn depth_first_follows_successors_in_order() {
+    let graph = diamond();
+
+    let order: String = Unweighted::new(&graph)
+        .depth_first(&['a'])
+        .into_iter()
+        .collect();
+
+    assert_eq!(order, "abdc");
+}
+
+#[test]
+fn reachable_only_follows_edge_directions() {
+    let graph = diamond();
+    let unweighted = Unweighted::new(&graph);
+
+    assert_eq!(unweighted.reachable(&['a']).len(), 4);
+    assert_eq!(unweighted.reachable(&['c']), ['c', 'd'].into());
+}
+
+#[test]
+fn distances_from_covers_every_reachable_node() {
+    let distances = diamond().distances_from(&['b']);
+
+    assert_eq!(distances, [('b', 0), ('d', 2)].into());
+}
+
+#[test]
+fn all_pairs_variants_agree() {
+    let graph = diamond();
+    let nodes = ['a', 'b', 'c', 'd'];
+
+    let table = graph.floyd_warshall(&nodes);
+
+    assert_eq!(table, graph.repeated_dijkstra(&nodes));
+    assert_eq!(table[&('a', 'd')], 3);
+    assert_eq!(table[&('c', 'c')], 0);
+    assert!(!table.contains_key(&('d', 'a')));
+}
+
+#[test]
+fn heat_loss_to_every_block() {
+    let base = sample_city();
+    let goal = base.goal();
+
+    let distances = RegularCityMap(&base).distances_from(&CruciblePathStep::seeds());
+    let to_goal = distances
+        .iter()
+        .filter(|(step, _)| step.position == goal)
+        .map(|(_, &heat_loss)| heat_loss)
+        .min();
+
+    assert_eq!(to_goal, Some(102));
+}
+
+#[test]
+fn bellman_ford_handles_negative_edges() {
+    let graph = Edges::from(&[('a', 'b', 4), ('a', 'c', 1), ('b', 'd', -3), ('c', 'd', 2)]);
+
+    let distances = graph.bellman_ford(&['a']).unwrap();
+
+    assert_eq!(distances, [('a', 0), ('b', 4), ('c', 1), ('d', 1)].into());
+    assert_eq!(
+        Unweighted::new(&graph).reachable(&['a']).len(),
+        distances.len()
+    );
+}
+
+#[test]
+fn bellman_ford_reports_negative_cycles() {
+    let graph = Edges::from(&[('a', 'b', 1), ('b', 'c', -2), ('c', 'd', 1), ('c', 'b', 1)]);
+
+    let cycle = graph.bellman_ford(&['a']).unwrap_err();
+    let mut nodes = cycle.nodes.clone();
+    nodes.sort();
+
+    assert_eq!(nodes, ['b', 'c']);
+    assert_eq!(graph.bellman_ford(&['d']), Ok([('d', 0)].into()));
+}
+
+#[test]
+fn topological_sort_orders_edges_forward() {
+    let order = diamond().topological_sort(&['a']).unwrap();
+    let position = |node| order.iter().position(|&n| n == node).unwrap();
+
+    assert_eq!(order.len(), 4);
+    assert!(position('a') < position('b') && position('b') < position('d'));
+    assert!(position('a') < position('c') && position('c') < position('d'));
+}
+
+#[test]
+fn topological_sort_reports_cycles() {
+    let graph = Edges::from(&[('a', 'b', 1), ('b', 'c', 1), ('c', 'a', 1), ('c', 'd', 1)]);
+
+    let cycle = graph.topological_sort(&['a']).unwrap_err();
+
+    assert_eq!(cycle.nodes, ['a', 'b', 'c']);
+    assert_eq!(graph.longest_distances(&['d']), Ok([('d', 0)].into()));
+}
+
+#[test]
+fn longest_distances_on_a_dag() {
+    let distances = diamond().longest_distances(&['a']).unwrap();
+
+    assert_eq!(distances, [('a', 0), ('b', 1), ('c', 2), ('d', 5)].into());
+}
+
+fn maze() -> Maze {
+    Maze(Grid::parse("...#\n.#..\n....\n#.#.\n", Some).unwrap())
+}
+
+#[test]
+fn longest_simple_path_through_a_maze() {
+    let maze = maze();
+    let start = UPoint2D::from(0, 0);
+    let goal = UPoint2D::from(3, 3);
+
+    let path = maze
+        .longest_simple_path(start, |&node| node == goal, 1000)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(path.distance, 8);
+    assert_eq!(path.nodes.len(), 9);
+    assert_eq!(path.nodes.first(), Some(&start));
+    assert_eq!(path.nodes.last(), Some(&goal));
+    assert!(path
+        .nodes
+        .windows(2)
+        .all(|pair| maze.neighbours(&pair[0]).contains(&(1, pair[1]))));
+    assert!(maze
+        .longest_simple_path(start, |&node| node == goal, 1)
+        .is_err());
+}
+
+#[test]
+fn compressed_graphs_keep_distances_between_junctions() {
+    let maze = maze();
+    let start = UPoint2D::from(0, 0);
+    let goal = UPoint2D::from(3, 3);
+
+    let compressed = maze.compress(&[start, goal]);
+    let junctions: Vec<_> = compressed.nodes().cloned().collect();
+
+    assert!(junctions.len() < maze.0.iter().filter(|(_, &c)| c == '.').count());
+    assert!(junctions.contains(&start) && junctions.contains(&goal));
+    assert_eq!(
+        compressed.repeated_dijkstra(&junctions),
+        maze.repeated_dijkstra(&junctions)
+    );
+    assert_eq!(
+        compressed
+            .longest_simple_path(start, |&node| node == goal, 1000)
+            .map(|path| path.map(|path| path.distance)),
+        Ok(Some(8))
+    );
+}
+
+#[test]
+fn adjacency_lists_in_both_modes() {
+    let edges = [('a', 'b', 1), ('b', 'c', 2)];
+    let directed = AdjacencyList::from_edges(EdgeMode::Directed, edges);
+    let undirected = AdjacencyList::from_edges(EdgeMode::Undirected, edges);
+
+    assert_eq!(directed.node_count(), 3);
+    assert_eq!(directed.edges().count(), 2);
+    assert_eq!(directed.distance(&['c'], |&node| node == 'a'), None);
+    assert_eq!(undirected.edges().count(), 4);
+    assert_eq!(undirected.distance(&['c'], |&node| node == 'a'), Some(3));
+}
+
+#[test]
+fn adjacency_list_removal() {
+    let mut graph = AdjacencyList::from_edges(
+        EdgeMode::Undirected,
+        [('a', 'b', 1), ('b', 'c', 1), ('a', 'c', 5)],
+    );
+
+    assert!(graph.remove_edge(&'c', &'a'));
+    assert!(!graph.remove_edge(&'a', &'c'));
+    assert_eq!(graph.distance(&['a'], |&node| node == 'c'), Some(2));
+
+    assert!(graph.remove_node(&'b'));
+    assert!(!graph.contains_node(&'b'));
+    assert_eq!(graph.edges().count(), 0);
+    assert_eq!(graph.distance(&['a'], |&node| node == 'c'), None);
+}
+
+#[test]
+fn adjacency_matrix_matches_the_list() {
+    let edges = [(0, 1, 1), (0, 2, 2), (1, 3, 2), (2, 3, 1), (0, 3, 5)];
+    let list = AdjacencyList::from_edges(EdgeMode::Directed, edges);
+    let matrix = AdjacencyMatrix::from_edges(EdgeMode::Directed, 4, edges);
+    let nodes: Vec<_> = matrix.nodes().collect();
+
+    assert_eq!(matrix.edges().count(), edges.len());
+    assert_eq!(matrix.edge(0, 3), Some(&5));
+    assert_eq!(matrix.edge(3, 0), None);
+    assert_eq!(
+        matrix.repeated_dijkstra(&nodes),
+        list.repeated_dijkstra(&nodes)
+    );
+}
+
+#[test]
+fn adjacency_matrix_nodes_can_be_added_and_removed() {
+    let mut matrix = AdjacencyMatrix::from_edges(EdgeMode::Undirected, 2, [(0, 1, 1)]);
+
+    let added = matrix.add_node();
+    matrix.add_edge(1, added, 2);
+    assert_eq!(added, 2);
+    assert_eq!(matrix.distance(&[0], |&node| node == added), Some(3));
+
+    // Node 2 moves down to take the place of node 1.
+    assert!(matrix.remove_node(1));
+    assert_eq!(matrix.node_count(), 2);
+    assert_eq!(matrix.edges().count(), 0);
+    assert!(!matrix.remove_edge(0, 1));
+
+    matrix.add_edge(1, 0, 4);
+    assert_eq!(matrix.edge(0, 1), Some(&4));
+}
+
+#[test]
+fn adjacency_matrix_ignores_removing_missing_nodes() {
+    let mut matrix = AdjacencyMatrix::from_edges(EdgeMode::Directed, 2, [(0, 1, 1)]);
+
+    assert!(!matrix.remove_node(5));
+    assert_eq!(matrix.node_count(), 2);
+    assert_eq!(matrix.edge(0, 1), Some(&1));
+}
+
+#[test]
+#[should_panic(expected = "outside of a graph with 2 nodes")]
+fn adjacency_matrix_add_edge_checks_nodes() {
+    let mut matrix = AdjacencyMatrix::new(EdgeMode::Directed, 2);
+
+    matrix.add_edge(0, 2, 7);
+}
+
+#[test]
+#[should_panic(expected = "outside of a graph with 2 nodes")]
+fn adjacency_matrix_remove_edge_checks_nodes() {
+    let mut matrix = AdjacencyMatrix::<u32>::new(EdgeMode::Undirected, 2);
+
+    matrix.remove_edge(2, 0);
+}
+
+#[test]
+#[should_panic(expected = "outside of a graph with 2 nodes")]
+fn adjacency_matrix_edge_checks_nodes() {
+    let matrix = AdjacencyMatrix::<u32>::new(EdgeMode::Directed, 2);
+
+    matrix.edge(1, 2);
+}
+
+#[test]
+#[should_panic(expected = "outside of a graph with 2 nodes")]
+fn adjacency_matrix_neighbours_check_nodes() {
+    let matrix = AdjacencyMatrix::<u32>::new(EdgeMode::Directed, 2);
+
+    matrix.neighbours(&2);
+}
+
+fn sorted<T: Clone + Ord>(mut groups: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    for group in &mut groups {
+        group.sort();
+    }
+    groups.sort();
+    groups
+}
+
+// Two triangles joined by the edge c-d, with g hanging off f.
+fn triangles() -> AdjacencyList<char, u32> {
+    AdjacencyList::from_edges(
+        EdgeMode::Undirected,
+        "ab bc ca cd de ef fd fg".split(' ').map(|edge| {
+            let mut ends = edge.chars();
+            (ends.next().unwrap(), ends.next().unwrap(), 1)
+        }),
+    )
+}
+
+#[test]
+fn strongly_connected_components_in_reverse_topological_order() {
+    let graph = AdjacencyList::from_edges(
+        EdgeMode::Directed,
+        [
+            ('a', 'b', 1),
+            ('b', 'c', 1),
+            ('c', 'a', 1),
+            ('c', 'd', 1),
+            ('d', 'e', 1),
+            ('e', 'd', 1),
+            ('f', 'a', 1),
+        ],
+    );
+
+    let components = Unweighted::new(&graph).strongly_connected_components(&['a', 'f']);
+
+    assert_eq!(
+        sorted(components.clone()),
+        [vec!['a', 'b', 'c'], vec!['d', 'e'], vec!['f']]
+    );
+    assert_eq!(components[0].len(), 2);
+    assert_eq!(components[2], ['f']);
+}
+
+#[test]
+fn bridges_and_articulation_points() {
+    let graph = triangles();
+    let unweighted = Unweighted::new(&graph);
+
+    let mut bridges: Vec<_> = unweighted
+        .bridges(&['a'])
+        .into_iter()
+        .map(|(from, to)| (from.min(to), from.max(to)))
+        .collect();
+    bridges.sort();
+    let mut points = unweighted.articulation_points(&['a']);
+    points.sort();
+
+    assert_eq!(bridges, [('c', 'd'), ('f', 'g')]);
+    assert_eq!(points, ['c', 'd', 'f']);
+    assert_eq!(unweighted.bridges(&['b']).len(), 2);
+    assert_eq!(unweighted.articulation_points(&['g']).len(), 3);
+}
+
+#[test]
+fn every_inner_node_of_a_long_path_is_an_articulation_point() {
+    let edges = (0..20_000u32).map(|node| (node, node + 1, 1));
+    let graph = AdjacencyList::from_edges(EdgeMode::Undirected, edges);
+
+    let points = Unweighted::new(&graph).articulation_points(&[0]);
+
+    assert_eq!(points.len(), 19_999);
+    assert!(!points.contains(&0) && !points.contains(&20_000));
+}
+
+#[test]
+fn parallel_edges_are_not_bridges() {
+    let graph = AdjacencyList::from_edges(EdgeMode::Undirected, [('a', 'b', 1), ('a', 'b', 1)]);
+
+    assert!(Unweighted::new(&graph).bridges(&['a']).is_empty());
+}
+
+#[test]
+fn connected_components_and_union_find_agree() {
+    let mut graph = triangles();
+    graph.remove_edge(&'c', &'d');
+    graph.add_node('h');
+    let mut union_find = UnionFind::from_nodes(graph.nodes().cloned());
+    for (from, to, _) in graph.edges() {
+        union_find.union(*from, *to);
+    }
+
+    let components = Unweighted::new(&graph).connected_components(&['a', 'd', 'g', 'h']);
+
+    assert_eq!(
+        sorted(components.clone()),
+        [vec!['a', 'b', 'c'], vec!['d', 'e', 'f', 'g'], vec!['h']]
+    );
+    assert_eq!(sorted(union_find.sets()), sorted(components));
+    assert_eq!(union_find.set_count(), 3);
+    assert_eq!(union_find.set_size('g'), 4);
+    assert!(union_find.connected('d', 'g'));
+    assert!(!union_find.connected('a', 'h'));
+    assert!(!union_find.union('e', 'g'));
+}
+
+#[test]
+fn max_flow_equals_min_cut() {
+    let graph = Edges::from(&[
+        ('s', 'a', 16),
+        ('s', 'b', 13),
+        ('a', 'c', 12),
+        ('b', 'a', 4),
+        ('b', 'd', 14),
+        ('c', 'b', 9),
+        ('c', 't', 20),
+        ('d', 'c', 7),
+        ('d', 't', 4),
+    ]);
+    let network = FlowNetwork::from_graph(&graph, &['s']);
+
+    let max_flow = network.max_flow('s', 't');
+    let mut cut_edges = max_flow.cut.edges.clone();
+    cut_edges.sort();
+    let out_of_source: u32 = max_flow
+        .flows
+        .iter()
+        .filter(|&&(from, _, _)| from == 's')
+        .map(|&(_, _, flow)| flow)
+        .sum();
+
+    assert_eq!(max_flow.value, 23);
+    assert_eq!(out_of_source, 23);
+    assert_eq!(max_flow.cut.capacity, 23);
+    assert_eq!(max_flow.cut.side, HashSet::from(['s', 'a', 'b', 'd']));
+    assert_eq!(cut_edges, [('a', 'c'), ('d', 'c'), ('d', 't')]);
+    assert_eq!(network.max_flow('t', 's').value, 0);
+}
+
+#[test]
+fn global_min_cut_separates_loosely_joined_clusters() {
+    let mut edges = vec![(1, 5, 1), (4, 8, 1)];
+    for cluster in [[1, 2, 3, 4], [5, 6, 7, 8]] {
+        for (i, &from) in cluster.iter().enumerate() {
+            edges.extend(cluster[i + 1..].iter().map(|&to| (from, to, 3)));
+        }
+    }
+    let graph = AdjacencyList::from_edges(EdgeMode::Undirected, edges);
+    let network = FlowNetwork::from_graph(&graph, &[1]);
+
+    let cut = network.global_min_cut().unwrap();
+    let mut cut_edges: Vec<_> = cut
+        .edges
+        .iter()
+        .map(|&(from, to)| (from.min(to), from.max(to)))
+        .collect();
+    cut_edges.sort();
+
+    assert_eq!(cut.capacity, 2);
+    assert_eq!(cut.side.len(), 4);
+    assert!(cut.side.contains(&1) != cut.side.contains(&5));
+    assert_eq!(cut_edges, [(1, 5), (4, 8)]);
+    assert!(FlowNetwork::from_graph(&graph, &[])
+        .global_min_cut()
+        .is_none());
+}
+
+#[test]
+fn dial_agrees_with_dijkstra_on_crucibles() {
+    let base = sample_city();
+    let goal = base.goal();
+    let regular = RegularCityMap(&base);
+    let ultra = UltracrucibleCityMap(&base);
+    let ultra_goal = |step: &CruciblePathStep| step.position == goal && step.consecutive >= 4;
+
+    let reachable: HashSet<_> = Unweighted::new(&ultra)
+        .reachable(&CruciblePathStep::seeds())
+        .iter()
+        .map(|step| ultra.index(step))
+        .collect();
+    let regular_distance =
+        regular.dial_distance(&CruciblePathStep::seeds(), |step| step.position == goal);
+
+    assert_eq!(regular_distance, Some(102));
+    assert_eq!(
+        ultra.dial_distance(&CruciblePathStep::seeds(), ultra_goal),
+        ultra.distance(&CruciblePathStep::seeds(), ultra_goal)
+    );
+    assert_eq!(
+        reachable.len(),
+        Unweighted::new(&ultra)
+            .reachable(&CruciblePathStep::seeds())
+            .len()
+    );
+    assert!(reachable.iter().all(|&index| index < ultra.node_count()));
+}
+
+#[test]
+fn dial_handles_zero_distance_edges() {
+    let matrix = AdjacencyMatrix::from_edges(
+        EdgeMode::Directed,
+        5,
+        [(0, 1, 0u32), (1, 2, 0), (0, 3, 1), (2, 3, 3), (3, 4, 0)],
+    );
+
+    assert_eq!(matrix.dial_distance(&[0], |&node| node == 2), Some(0));
+    assert_eq!(matrix.dial_distance(&[0], |&node| node == 4), Some(1));
+    assert_eq!(matrix.dial_distance(&[4], |&node| node == 0), None);
+}
+
+#[test]
+fn dial_agrees_with_dijkstra_as_edges_grow_longer() {
+    // Longer edges show up only after shorter ones are pending, so the circle
+    // of buckets grows while it holds nodes.
+    let edges = (0..12).flat_map(|from| {
+        (0..12)
+            .filter(move |to| (from * 5 + to * 3) % 4 == 0)
+            .map(move |to| (from, to, (from * 7 + to * 13) % 20))
+    });
+    let matrix = AdjacencyMatrix::from_edges(EdgeMode::Directed, 12, edges);
+
+    for goal in matrix.nodes() {
+        assert_eq!(
+            matrix.dial_distance(&[0], |&node| node == goal),
+            matrix.distance(&[0], |&node| node == goal),
+            "goal {goal}"
+        );
+    }
+}
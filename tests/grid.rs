@@ -0,0 +1,90 @@
+use aoc_2023::common::Grid;
+use aoc_2023::parse::ParseErrorKind;
+use aoc_2023::spatial::Orientation;
+use aoc_2023::spatial::UPoint2D;
+
+const SAMPLE: &str = "abc\ndef\n";
+
+fn sample() -> Grid<char> {
+    Grid::parse(SAMPLE, Some).unwrap()
+}
+
+#[test]
+fn parse_and_display_round_trip() {
+    let grid = sample();
+
+    assert_eq!((grid.get_width(), grid.get_height()), (3, 2));
+    assert_eq!(grid.to_string(), SAMPLE);
+}
+
+#[test]
+fn parse_reports_unexpected_characters_and_ragged_rows() {
+    let digits = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!((digits.line, digits.column), (2, 2));
+    assert_eq!(digits.kind, ParseErrorKind::UnexpectedCharacter('x'));
+
+    let ragged = Grid::parse("abc\nde\n", Some).unwrap_err();
+    assert_eq!((ragged.line, ragged.column), (2, 3));
+}
+
+#[test]
+fn get_is_bounds_checked() {
+    let mut grid = sample();
+
+    assert_eq!(grid.get(UPoint2D::from(1, 2)), Some(&'f'));
+    assert_eq!(grid.get(UPoint2D::from(2, 0)), None);
+    assert_eq!(grid.get(UPoint2D::from(0, 3)), None);
+    assert_eq!(grid.get(UPoint2D { row: 0, column: 1 }), None);
+
+    *grid.at_mut(UPoint2D::from(0, 0)) = 'z';
+    assert_eq!(grid.to_string(), "zbc\ndef\n");
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = sample();
+
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+
+    assert_eq!(rows, ["abc", "def"]);
+    assert_eq!(columns, ["ad", "be", "cf"]);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = sample();
+
+    assert_eq!(grid.neighbours(UPoint2D::from(0, 0)).len(), 2);
+    assert_eq!(grid.neighbours(UPoint2D::from(0, 1)).len(), 3);
+    assert_eq!(grid.neighbours8(UPoint2D::from(0, 0)).len(), 3);
+    assert_eq!(grid.neighbours8(UPoint2D::from(1, 1)).len(), 5);
+}
+
+#[test]
+fn transformations() {
+    let grid = sample();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(
+        grid.rotate(Orientation::Clockwise).to_string(),
+        "da\neb\nfc\n"
+    );
+    assert_eq!(
+        grid.rotate(Orientation::Counterclockwise).to_string(),
+        "cf\nbe\nad\n"
+    );
+    assert_eq!(grid.flip_horizontally().to_string(), "cba\nfed\n");
+    assert_eq!(grid.flip_vertically().to_string(), "def\nabc\n");
+}
+
+#[test]
+fn flood_fill_stays_within_passable_cells() {
+    let grid = Grid::parse("..#.\n.##.\n#...\n", Some).unwrap();
+
+    let filled = grid.flood_fill(&[UPoint2D::from(0, 0)], |&c| c == '.');
+    let walled = grid.flood_fill(&[UPoint2D::from(0, 2)], |&c| c == '.');
+
+    assert_eq!(filled.len(), 3);
+    assert!(walled.is_empty());
+}
@@ -0,0 +1,115 @@
+use aoc_2023::day1::Day1;
+use aoc_2023::parse::ParseError;
+use aoc_2023::parse::ParseErrorKind;
+use aoc_2023::Solution;
+
+// Inputs that parse fine but have no answer should report an error rather
+// than panic.
+#[test]
+fn unsolvable_puzzles_are_errors() {
+    let cases = [
+        (10, "S.\n..\n"),
+        (10, ".|.\n-S-\n.|.\n"),
+        (10, "S-7\n|.|\nL--\n"),
+        (13, "#.\n.#\n"),
+    ];
+
+    for (number, input) in cases {
+        let puzzle = aoc_2023::find_day(number).unwrap().parse(input).unwrap();
+
+        assert!(puzzle.part_one().is_err(), "day {number}: {input:?}");
+        assert!(puzzle.part_two().is_err(), "day {number}: {input:?}");
+    }
+}
+
+#[test]
+fn day1_rejects_unexpected_characters() {
+    let error = Day1::parse("one2\na1éb2\n").unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError {
+            line: 2,
+            column: 3,
+            kind: ParseErrorKind::UnexpectedCharacter('é'),
+        })
+    );
+}
+
+#[test]
+fn every_day_reports_where_its_input_is_malformed() {
+    use ParseErrorKind::*;
+
+    let cases = [
+        (
+            2,
+            "Game 1: 3 blue\nGame 2: 3 blue; 4 purple\n",
+            2,
+            19,
+            UnexpectedToken("purple".to_string()),
+        ),
+        (
+            3,
+            "€.*\n..\n",
+            2,
+            3,
+            Expected("all rows to have the same length"),
+        ),
+        (
+            4,
+            "Card 1: 41 48 | 83\nCard 2: 13 32 61\n",
+            2,
+            17,
+            MissingSeparator("|"),
+        ),
+        (
+            5,
+            "seeds: 79 1x\n\nseed-to-soil map:\n50 98 2\n",
+            1,
+            11,
+            InvalidNumber("1x".to_string()),
+        ),
+        (
+            6,
+            "Time:      7  15\nDistance:  9  4O\n",
+            2,
+            15,
+            InvalidNumber("4O".to_string()),
+        ),
+        (7, "32T3K 765\nT55X5 684\n", 2, 4, UnexpectedCharacter('X')),
+        (
+            8,
+            "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD EEE)\n",
+            4,
+            15,
+            MissingSeparator(", "),
+        ),
+        (9, "0 3 6\n1 x 6\n", 2, 3, InvalidNumber("x".to_string())),
+        (10, "S-7\n|.X\nL-J\n", 2, 3, UnexpectedCharacter('X')),
+        (11, "#..\n.x.\n", 2, 2, UnexpectedCharacter('x')),
+        (
+            12,
+            "???.### 1,1,3\n.??..??...?##. 1,x,3\n",
+            2,
+            18,
+            InvalidNumber("x".to_string()),
+        ),
+        (13, "#.\n.#\n\n#.\n.?\n", 5, 2, UnexpectedCharacter('?')),
+        (14, "O.\n.X\n", 2, 2, UnexpectedCharacter('X')),
+        (15, "rn=1,cm*2\n", 1, 8, UnexpectedCharacter('*')),
+        (16, ".|\n/x\n", 2, 2, UnexpectedCharacter('x')),
+        (17, "12\n3x\n", 2, 2, UnexpectedCharacter('x')),
+    ];
+
+    for (number, input, line, column, kind) in cases {
+        let Err(error) = aoc_2023::find_day(number).unwrap().parse(input) else {
+            panic!("day {number} accepted {input:?}");
+        };
+
+        assert_eq!(
+            error.downcast_ref::<ParseError>(),
+            Some(&ParseError { line, column, kind }),
+            "day {number}: {input:?}"
+        );
+    }
+}
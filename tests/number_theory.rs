@@ -0,0 +1,66 @@
+use aoc_2023::number_theory::extended_gcd;
+use aoc_2023::number_theory::mod_inverse;
+use aoc_2023::number_theory::solve_congruences;
+use aoc_2023::number_theory::Congruence;
+use aoc_2023::number_theory::InconsistentCongruences;
+
+#[test]
+fn extended_gcd_gives_bezout_coefficients() {
+    for a in -30i64..30 {
+        for b in -30i64..30 {
+            let (gcd, x, y) = extended_gcd(a, b);
+
+            assert_eq!(gcd, num::integer::gcd(a, b));
+            assert_eq!(a * x + b * y, gcd);
+        }
+    }
+}
+
+#[test]
+fn mod_inverse_only_for_coprime_numbers() {
+    assert_eq!(mod_inverse(3, 7), Some(5));
+    assert_eq!(mod_inverse(-3, 7), Some(2));
+    assert_eq!(mod_inverse(6, 9), None);
+}
+
+#[test]
+fn congruences_with_coprime_moduli() {
+    let congruences = [
+        Congruence::new(2, 3),
+        Congruence::new(3, 5),
+        Congruence::new(2, 7),
+    ];
+
+    assert_eq!(
+        solve_congruences(&congruences),
+        Ok(Congruence::new(23, 105))
+    );
+    assert_eq!(solve_congruences::<i64>(&[]), Ok(Congruence::new(0, 1)));
+}
+
+#[test]
+fn congruences_with_shared_factors_match_brute_force() {
+    for first in 0..6i64 {
+        for second in 0..10 {
+            let congruences = [Congruence::new(first, 6), Congruence::new(second, 10)];
+            let brute_force = (0..30).find(|&x| congruences.iter().all(|c| c.contains(x)));
+
+            let solution = solve_congruences(&congruences);
+
+            match brute_force {
+                Some(x) => assert_eq!(solution, Ok(Congruence::new(x, 30))),
+                None => assert_eq!(solution, Err(InconsistentCongruences { index: 1 })),
+            }
+        }
+    }
+}
+
+#[test]
+fn first_at_least() {
+    let congruence = Congruence::new(4, 6);
+
+    assert_eq!(congruence.first_at_least(0), 4);
+    assert_eq!(congruence.first_at_least(4), 4);
+    assert_eq!(congruence.first_at_least(5), 10);
+    assert_eq!(congruence.first_at_least(-3), -2);
+}
@@ -0,0 +1,148 @@
+use aoc_2023::spatial::Compass;
+use aoc_2023::spatial::Direction;
+use aoc_2023::spatial::Orientation;
+use aoc_2023::spatial::Point2D;
+use aoc_2023::spatial::PointConversionError;
+use aoc_2023::spatial::Rectangle;
+use aoc_2023::spatial::UPoint2D;
+use aoc_2023::spatial::Vector2D;
+
+fn cells(rectangle: Rectangle) -> Vec<(usize, usize)> {
+    rectangle
+        .perimeter()
+        .map(|point| (point.row - 1, point.column - 1))
+        .collect()
+}
+
+#[test]
+fn perimeter_walks_clockwise_once_per_cell() {
+    let rectangle = Rectangle::new(UPoint2D::from(0, 0), UPoint2D::from(2, 2));
+
+    assert_eq!(
+        cells(rectangle),
+        [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (1, 0)
+        ]
+    );
+}
+
+#[test]
+fn perimeter_of_degenerate_rectangles() {
+    let single = Rectangle::new(UPoint2D::from(1, 1), UPoint2D::from(1, 1));
+    let row = Rectangle::new(UPoint2D::from(1, 1), UPoint2D::from(1, 3));
+    let column = Rectangle::new(UPoint2D::from(1, 1), UPoint2D::from(3, 1));
+
+    assert_eq!(cells(single), [(1, 1)]);
+    assert_eq!(cells(row), [(1, 1), (1, 2), (1, 3)]);
+    assert_eq!(cells(column), [(1, 1), (2, 1), (3, 1)]);
+}
+
+#[test]
+fn grown_perimeter_skips_the_padding() {
+    let number = Rectangle::new(UPoint2D::from(0, 0), UPoint2D::from(0, 1));
+
+    assert_eq!(cells(number.grow(1)), [(0, 2), (1, 2), (1, 1), (1, 0)]);
+}
+
+#[test]
+fn neighbours8_skip_the_padding() {
+    assert_eq!(UPoint2D::from(0, 0).neighbours8().len(), 3);
+    assert_eq!(UPoint2D::from(1, 1).neighbours8().len(), 8);
+}
+
+#[test]
+fn compass_rotation() {
+    assert_eq!(
+        Compass::North.rotate45(Orientation::Clockwise),
+        Compass::NorthEast
+    );
+    assert_eq!(
+        Compass::North.rotate45(Orientation::Counterclockwise),
+        Compass::NorthWest
+    );
+    assert_eq!(
+        Compass::West.rotate90(Orientation::Clockwise),
+        Compass::North
+    );
+    assert!(Compass::SouthWest.is_diagonal());
+    assert!(!Compass::South.is_diagonal());
+}
+
+#[test]
+fn point_and_vector_arithmetic() {
+    let point = Point2D::new(3i64, -2);
+    let other = Point2D::new(-1, 4);
+    let vector = Vector2D::new(2i64, 5);
+
+    assert_eq!(point + vector, Point2D::new(5, 3));
+    assert_eq!(point - vector, Point2D::new(1, -7));
+    assert_eq!(point - other, Vector2D::new(4, -6));
+    assert_eq!(point * 3, Point2D::new(9, -6));
+    assert_eq!(vector + Vector2D::new(-1, -1), Vector2D::new(1, 4));
+    assert_eq!(vector - Vector2D::new(-1, -1), Vector2D::new(3, 6));
+    assert_eq!(vector * -2, Vector2D::new(-4, -10));
+    assert_eq!(-vector, Vector2D::new(-2, -5));
+    assert_eq!(-Direction::Left, Direction::Right);
+    assert_eq!(point.move_by(Direction::Up), Point2D::new(2, -2));
+}
+
+#[test]
+fn distances() {
+    let point = Point2D::new(3i64, -2);
+    let other = Point2D::new(-1, 4);
+
+    assert_eq!(point.manhattan_distance(other), 10);
+    assert_eq!(point.chebyshev_distance(other), 6);
+    assert_eq!((point - other).manhattan_length(), 10);
+    assert_eq!((point - other).chebyshev_length(), 6);
+    assert_eq!(point.manhattan_distance(point), 0);
+}
+
+#[test]
+fn conversions_strip_and_restore_the_padding() {
+    let padded = UPoint2D::from(2, 5);
+
+    assert_eq!(Point2D::<i32>::try_from(padded), Ok(Point2D::new(2, 5)));
+    assert_eq!(UPoint2D::try_from(Point2D::new(2i32, 5)), Ok(padded));
+    assert_eq!(
+        UPoint2D::try_from(Point2D::new(0i64, 0)),
+        Ok(UPoint2D::from(0, 0))
+    );
+}
+
+#[test]
+fn conversions_reject_unrepresentable_points() {
+    let on_padding_row = UPoint2D { row: 0, column: 3 };
+    let on_padding_column = UPoint2D { row: 3, column: 0 };
+
+    assert_eq!(
+        Point2D::<i64>::try_from(on_padding_row),
+        Err(PointConversionError::Padding)
+    );
+    assert_eq!(
+        Point2D::<i64>::try_from(on_padding_column),
+        Err(PointConversionError::Padding)
+    );
+    assert_eq!(
+        Point2D::<i8>::try_from(UPoint2D::from(200, 0)),
+        Err(PointConversionError::OutOfRange)
+    );
+    assert_eq!(
+        UPoint2D::try_from(Point2D::new(-1i64, 0)),
+        Err(PointConversionError::OutOfRange)
+    );
+    assert_eq!(
+        UPoint2D::try_from(Point2D {
+            row: 0,
+            column: usize::MAX
+        }),
+        Err(PointConversionError::OutOfRange)
+    );
+}